@@ -0,0 +1,67 @@
+//! Generates a module that includes every solution in `src/bin` and registers it with the runner,
+//! so the main binary can run all days in-process.
+use std::{env, fs, path::Path};
+
+/// Solutions are named `<year>-<day>.rs`, e.g. `2023-01.rs`.
+fn is_solution(name: &str) -> bool {
+    let Some(stem) = name.strip_suffix(".rs") else {
+        return false;
+    };
+    let Some((year, day)) = stem.split_once('-') else {
+        return false;
+    };
+    year.len() == 4
+        && day.len() == 2
+        && year.chars().all(|c| c.is_ascii_digit())
+        && day.chars().all(|c| c.is_ascii_digit())
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/bin");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+    let mut names: Vec<String> = fs::read_dir(&bin_dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+                .filter(|name| is_solution(name))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    names.sort();
+
+    let mut modules = String::new();
+    let mut entries = String::new();
+
+    for name in &names {
+        let module = format!(
+            "solution_{}",
+            name.trim_end_matches(".rs").replace('-', "_")
+        );
+        let path = bin_dir.join(name);
+
+        // solutions are linted when they are built as binaries, don't report the same warnings twice.
+        modules.push_str(&format!(
+            "#[cfg(not(test))]\n#[allow(warnings)]\n#[path = {:?}]\nmod {module};\n",
+            path.display().to_string()
+        ));
+        entries.push_str(&format!("    {module}::SOLUTION,\n"));
+    }
+
+    let contents = format!(
+        "{modules}\n\
+         /// Every solution found in `src/bin`, sorted by puzzle.\n\
+         #[cfg(not(test))]\n\
+         pub static REGISTRY: advent_of_code::template::registry::Registry =\n    \
+         advent_of_code::template::registry::Registry::new(&[\n{entries}]);\n\n\
+         #[cfg(test)]\n\
+         pub static REGISTRY: advent_of_code::template::registry::Registry =\n    \
+         advent_of_code::template::registry::Registry::new(&[]);\n"
+    );
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("solutions.rs");
+    fs::write(out_path, contents).unwrap();
+}
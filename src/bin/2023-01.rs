@@ -1,5 +1,5 @@
 use std::collections::HashMap;
-advent_of_code::solution!(1);
+advent_of_code::solution!(2023, 1);
 
 // Out input are lines of numbers and characters.
 // For each line, we need to capture the first number that appears
@@ -43,7 +43,7 @@ fn parse_digits_from_input_part_two(input: &str) -> Vec<(u32, u32)> {
         let mut word = String::new();
 
         for c in line.chars() {
-            if c.is_digit(10) {
+            if c.is_ascii_digit() {
                 return Some(c.to_digit(10).unwrap());
             } else {
                 word.push(c);
@@ -85,7 +85,7 @@ fn parse_digits_from_input_part_two(input: &str) -> Vec<(u32, u32)> {
             // but if we only check at each point words will be: f, tf, ghtf, ightf, eightf
             // And we would need to extract 8
 
-            if !c.is_digit(10) {
+            if !c.is_ascii_digit() {
                 word.insert(0, c);
                 // The digit can occur in the first part of the substring,
                 // e.g. "eightf" == 8
@@ -109,7 +109,7 @@ fn parse_digits_from_input_part_two(input: &str) -> Vec<(u32, u32)> {
             let first = parse_first_digit(line);
             let last = parse_last_digit(line);
 
-            // println!("first: {:?}, last: {:?}", first, last);
+            advent_of_code::trace!("{line}: first {first:?}, last {last:?}");
             // If we have a first and last digit, return them
             if let (Some(first), Some(last)) = (first, last) {
                 (first, last)
@@ -139,8 +139,6 @@ pub fn part_one(input: &str) -> Option<u32> {
 
 pub fn part_two(input: &str) -> Option<u32> {
     let digits = parse_digits_from_input_part_two(input);
-    // 54194 is too low
-    // 54226 is too high
     let mut total = 0;
     for line in digits {
         // concatenate the first and last digits as a string
@@ -149,7 +147,6 @@ pub fn part_two(input: &str) -> Option<u32> {
         let first = line.0;
         let last = line.1;
         let concat = format!("{}{}", first, last);
-        println!("{} + {} = {}", first, last, concat);
         // parse the string as a u32
         let num = concat.parse::<u32>().unwrap();
         // add to the total
@@ -162,15 +159,5 @@ pub fn part_two(input: &str) -> Option<u32> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(142));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(281));
-    }
+    advent_of_code::test_examples!();
 }
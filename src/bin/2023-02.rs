@@ -0,0 +1,129 @@
+use advent_of_code::parse::{lines, ParseError, Span};
+
+advent_of_code::solution!(2023, 2, parse?);
+
+#[derive(Debug, Default)]
+struct CubeSet {
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+
+impl CubeSet {
+    fn check_possible(&self, criteria: &CubeSet) -> bool {
+        // Check if this pull is possible given the criteria
+        // All colors must be less than or equal to the criteria
+        self.red <= criteria.red && self.green <= criteria.green && self.blue <= criteria.blue
+    }
+    fn power(&self) -> u32 {
+        // Calculate the power of this pull
+        // The power is the product of all colors
+        self.red * self.green * self.blue
+    }
+}
+
+impl TryFrom<Span<'_>> for CubeSet {
+    type Error = ParseError;
+
+    fn try_from(s: Span) -> Result<Self, Self::Error> {
+        // Parse a pull, e.g. `3 blue, 4 red` is CubeSet { red: 4, green: 0, blue: 3 }
+        let mut cubes = CubeSet::default();
+
+        for cube in s.split(",") {
+            let (count, color) = cube.trim().split_once(" ")?;
+            let count = count.number()?;
+            match color.as_str() {
+                "red" => cubes.red = count,
+                "green" => cubes.green = count,
+                "blue" => cubes.blue = count,
+                _ => return Err(color.error(format!("invalid color `{color}`"))),
+            }
+        }
+
+        Ok(cubes)
+    }
+}
+
+#[derive(Debug)]
+pub struct Game {
+    id: u32,
+    cubes: Vec<CubeSet>,
+}
+
+impl Game {
+    fn min_cubes(&self) -> CubeSet {
+        // The minimum number of cubes that would make this game possible
+        // is the max for each color across all pulls
+        self.cubes
+            .iter()
+            .fold(CubeSet::default(), |min, pull| CubeSet {
+                red: min.red.max(pull.red),
+                green: min.green.max(pull.green),
+                blue: min.blue.max(pull.blue),
+            })
+    }
+}
+
+impl TryFrom<Span<'_>> for Game {
+    type Error = ParseError;
+
+    fn try_from(s: Span) -> Result<Self, Self::Error> {
+        // Parse a game with its pulls separated by `;`, e.g.
+        // Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+        let (key, pulls) = s.key_value()?;
+        let id = key.strip_prefix("Game")?.number()?;
+        let cubes = pulls
+            .split(";")
+            .map(CubeSet::try_from)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { id, cubes })
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+    lines(input).map(Game::try_from).collect()
+}
+
+pub fn part_one(games: &[Game]) -> Option<u32> {
+    let criteria = CubeSet {
+        red: 12,
+        green: 13,
+        blue: 14,
+    };
+    // A game is possible if all of its pulls are, sum the IDs of those games
+    let sum = games
+        .iter()
+        .filter(|game| game.cubes.iter().all(|pull| pull.check_possible(&criteria)))
+        .map(|game| game.id)
+        .sum();
+
+    Some(sum)
+}
+
+pub fn part_two(games: &[Game]) -> Option<u32> {
+    // Find the minimum number of cubes that would make each game possible
+    // Calculate the power of that CubeSet for each game and sum them
+    let sum = games.iter().map(|game| game.min_cubes().power()).sum();
+
+    Some(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result =
+            part_one(&parse(&advent_of_code::template::read_file("examples", PUZZLE)).unwrap());
+        assert_eq!(result, Some(8));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result =
+            part_two(&parse(&advent_of_code::template::read_file("examples", PUZZLE)).unwrap());
+        assert_eq!(result, Some(2286));
+    }
+}
@@ -0,0 +1,80 @@
+use advent_of_code::grid::{Grid, Run};
+
+advent_of_code::solution!(2023, 3, parse);
+
+pub fn parse(input: &str) -> Grid<char> {
+    input.parse().unwrap()
+}
+
+fn is_symbol(c: &char) -> bool {
+    !c.is_ascii_digit() && *c != '.'
+}
+
+fn value(grid: &Grid<char>, run: Run) -> u32 {
+    run.points()
+        .fold(0, |n, point| n * 10 + grid[point].to_digit(10).unwrap())
+}
+
+pub fn part_one(grid: &Grid<char>) -> Option<u32> {
+    // Sum the numbers that have a symbol anywhere around one of their digits.
+    let total = grid
+        .runs(char::is_ascii_digit)
+        .into_iter()
+        .filter(|run| {
+            run.points()
+                .flat_map(|point| grid.neighbors8(point))
+                .any(|neighbor| is_symbol(&grid[neighbor]))
+        })
+        .map(|run| value(grid, run))
+        .sum();
+
+    Some(total)
+}
+
+pub fn part_two(grid: &Grid<char>) -> Option<u32> {
+    // A gear is a `*` next to exactly two numbers, its ratio is their product.
+    let total = grid
+        .iter()
+        .filter(|(_, &c)| c == '*')
+        .filter_map(|(point, _)| {
+            let mut numbers: Vec<Run> = grid
+                .neighbors8(point)
+                .filter_map(|neighbor| grid.run_at(neighbor, char::is_ascii_digit))
+                .collect();
+            numbers.sort();
+            numbers.dedup();
+
+            match numbers[..] {
+                [a, b] => {
+                    let ratio = value(grid, a) * value(grid, b);
+                    advent_of_code::trace!("gear at {point} with ratio {ratio}");
+                    Some(ratio)
+                }
+                _ => None,
+            }
+        })
+        .sum();
+
+    Some(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&parse(&advent_of_code::template::read_file(
+            "examples", PUZZLE,
+        )));
+        assert_eq!(result, Some(4361));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&parse(&advent_of_code::template::read_file(
+            "examples", PUZZLE,
+        )));
+        assert_eq!(result, Some(467835));
+    }
+}
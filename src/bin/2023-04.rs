@@ -0,0 +1,91 @@
+use advent_of_code::parse::{lines, ParseError};
+
+advent_of_code::solution!(2023, 4, parse_input?);
+
+#[derive(Debug)]
+pub struct Card {
+    winners: Vec<u32>,
+    numbers: Vec<u32>,
+}
+
+impl Card {
+    fn count_winning_numbers(&self) -> u32 {
+        // Return the numbers that are winners
+        self.winners
+            .iter()
+            .filter(|&x| self.numbers.contains(x))
+            .count() as u32
+    }
+    fn value(&self) -> u32 {
+        // Identify how many winners are in the numbers
+        // and return 2^winners unless there are no winners
+        // return 0
+        let winners = self
+            .winners
+            .iter()
+            .filter(|&x| self.numbers.contains(x))
+            .count();
+        if winners == 0 {
+            return 0;
+        }
+        2u32.pow(winners as u32 - 1)
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Card>, ParseError> {
+    // Parse a line into a Card with ID, Winners, Numbers
+    // Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+    lines(input)
+        .map(|line| {
+            let (key, values) = line.key_value()?;
+            let (winners, numbers) = values.split_once("|")?;
+            key.strip_prefix("Card")?.number::<u32>()?;
+            Ok(Card {
+                winners: winners.numbers()?,
+                numbers: numbers.numbers()?,
+            })
+        })
+        .collect()
+}
+
+pub fn part_one(cards: &[Card]) -> Option<u32> {
+    let mut total = 0;
+    for card in cards {
+        total += card.value();
+    }
+    Some(total)
+}
+
+pub fn part_two(cards: &[Card]) -> Option<u32> {
+    // Every copy of a card wins one copy of each of the next `winners` cards
+    let mut repeats: Vec<u32> = vec![1; cards.len()];
+    for (i, card) in cards.iter().enumerate() {
+        let winners = card.count_winning_numbers() as usize;
+        let copies = repeats[i];
+        for repeat in repeats.iter_mut().skip(i + 1).take(winners) {
+            *repeat += copies;
+        }
+    }
+    Some(repeats.iter().sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(
+            &parse_input(&advent_of_code::template::read_file("examples", PUZZLE)).unwrap(),
+        );
+        assert_eq!(result, Some(13));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(
+            &parse_input(&advent_of_code::template::read_file("examples", PUZZLE)).unwrap(),
+        );
+        assert_eq!(result, Some(30));
+    }
+}
@@ -0,0 +1,88 @@
+use advent_of_code::intervals::{IntervalSet, PiecewiseMap};
+use advent_of_code::parse::{sections, ParseError};
+
+advent_of_code::solution!(2023, 5, parse?);
+
+#[derive(Debug)]
+pub struct Almanac {
+    seeds: Vec<i64>,
+    maps: Vec<PiecewiseMap>,
+}
+
+impl Almanac {
+    /// The seed-to-location map, composed of all the maps in order.
+    fn location_map(&self) -> PiecewiseMap {
+        self.maps
+            .iter()
+            .fold(PiecewiseMap::new(), |composed, map| composed.then(map))
+    }
+
+    /// The values in seeds are actually ranges: each pair represents a starting point
+    /// and the number of seeds in that range, e.g. `79 14` is `79..93`.
+    fn seed_ranges(&self) -> IntervalSet {
+        self.seeds
+            .chunks_exact(2)
+            .map(|pair| pair[0]..pair[0] + pair[1])
+            .collect()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Almanac, ParseError> {
+    // The seeds are the first section, e.g. `seeds: 79 14 55 13`. Every following section is a map
+    // with a name on its first line and one `destination source length` mapping per line.
+    let sections = sections(input);
+    let Some((seeds, maps)) = sections.split_first() else {
+        return Ok(Almanac {
+            seeds: vec![],
+            maps: vec![],
+        });
+    };
+
+    let seeds = seeds[0].strip_prefix("seeds:")?.numbers()?;
+    let maps = maps
+        .iter()
+        .map(|section| {
+            let mut map = PiecewiseMap::new();
+            for line in &section[1..] {
+                let numbers: Vec<i64> = line.numbers()?;
+                let [destination, source, length] = numbers[..] else {
+                    return Err(line.error("expected `destination source length`"));
+                };
+                map.insert(source..source + length, destination);
+            }
+            Ok(map)
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Almanac { seeds, maps })
+}
+
+pub fn part_one(almanac: &Almanac) -> Option<u64> {
+    let map = almanac.location_map();
+    almanac.seeds.iter().map(|&seed| map.get(seed) as u64).min()
+}
+
+pub fn part_two(almanac: &Almanac) -> Option<u64> {
+    // Map whole seed ranges at once instead of every seed.
+    let locations = almanac.location_map().map_set(&almanac.seed_ranges());
+    locations.min().map(|location| location as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result =
+            part_one(&parse(&advent_of_code::template::read_file("examples", PUZZLE)).unwrap());
+        assert_eq!(result, Some(35));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result =
+            part_two(&parse(&advent_of_code::template::read_file("examples", PUZZLE)).unwrap());
+        assert_eq!(result, Some(46));
+    }
+}
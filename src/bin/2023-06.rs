@@ -0,0 +1,100 @@
+use advent_of_code::math::quadratic_below_zero;
+use advent_of_code::parse::{lines, ParseError, Span};
+
+advent_of_code::solution!(2023, 6, parse?);
+
+#[derive(Debug)]
+pub struct Race {
+    time: u64,
+    distance: u64,
+}
+
+impl Race {
+    fn count_winners(&self) -> u64 {
+        // Holding the button for `hold` ms makes the boat travel `hold * (time - hold)` mm,
+        // so the winning holds are the integers where `hold² - time * hold + distance < 0`
+        quadratic_below_zero(1, -(self.time as i64), self.distance as i64)
+            .map_or(0, |holds| (holds.end() - holds.start() + 1) as u64)
+    }
+}
+
+#[derive(Debug)]
+pub struct Sheet {
+    times: Vec<u64>,
+    distances: Vec<u64>,
+}
+
+impl Sheet {
+    fn races(&self) -> impl Iterator<Item = Race> + '_ {
+        self.times
+            .iter()
+            .zip(&self.distances)
+            .map(|(&time, &distance)| Race { time, distance })
+    }
+
+    fn concatenated_race(&self) -> Race {
+        // The numbers are actually a single race whose digits have been split over several columns
+        // e.g. times of 7 15 30 are a time of 71530
+        let concat = |numbers: &[u64]| {
+            numbers
+                .iter()
+                .map(u64::to_string)
+                .collect::<String>()
+                .parse()
+                .unwrap()
+        };
+        Race {
+            time: concat(&self.times),
+            distance: concat(&self.distances),
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Sheet, ParseError> {
+    // Time:      7  15   30
+    // Distance:  9  40  200
+    let lines: Vec<Span> = lines(input).collect();
+    let [times, distances] = lines[..] else {
+        return Err(ParseError {
+            line: 1,
+            column: 1,
+            message: format!(
+                "expected a `Time` and a `Distance` line, found {} lines",
+                lines.len()
+            ),
+        });
+    };
+
+    Ok(Sheet {
+        times: times.strip_prefix("Time:")?.numbers()?,
+        distances: distances.strip_prefix("Distance:")?.numbers()?,
+    })
+}
+
+pub fn part_one(sheet: &Sheet) -> Option<u64> {
+    // Multiply the number of ways to win of all races together
+    Some(sheet.races().map(|race| race.count_winners()).product())
+}
+
+pub fn part_two(sheet: &Sheet) -> Option<u64> {
+    Some(sheet.concatenated_race().count_winners())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result =
+            part_one(&parse(&advent_of_code::template::read_file("examples", PUZZLE)).unwrap());
+        assert_eq!(result, Some(288));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result =
+            part_two(&parse(&advent_of_code::template::read_file("examples", PUZZLE)).unwrap());
+        assert_eq!(result, Some(71503));
+    }
+}
@@ -0,0 +1,79 @@
+use advent_of_code::cards::{Ranking, RankingError, Wildcard};
+use advent_of_code::parse::{lines, ParseError};
+
+advent_of_code::solution!(2023, 7, parse?);
+
+const CARD_ORDER: &str = "23456789TJQKA";
+
+#[derive(Debug, Clone)]
+pub struct Hand<'a> {
+    cards: &'a str,
+    bid: u32,
+}
+
+pub fn parse(input: &str) -> Result<Vec<Hand<'_>>, ParseError> {
+    // Parse input into hands. Input is a list of the 5 cards, a space, then the bid
+    // 32T3K 765
+    // T55J5 684
+    lines(input)
+        .map(|line| {
+            let (cards, bid) = line.split_once(" ")?;
+            Ok(Hand {
+                cards: cards.as_str(),
+                bid: bid.number()?,
+            })
+        })
+        .collect()
+}
+
+fn total_winnings(hands: &[Hand], ranking: &Ranking) -> Result<u32, RankingError> {
+    // Rank all hands from the weakest to the strongest, the winnings of a hand are its bid * its rank
+    let mut ranked: Vec<_> = hands
+        .iter()
+        .map(|hand| Ok((ranking.key(hand.cards)?, hand.bid)))
+        .collect::<Result<_, RankingError>>()?;
+    ranked.sort_unstable();
+
+    Ok(ranked
+        .iter()
+        .enumerate()
+        .map(|(rank, (_, bid))| bid * (rank as u32 + 1))
+        .sum())
+}
+
+pub fn part_one(hands: &[Hand]) -> Result<u32, RankingError> {
+    total_winnings(hands, &Ranking::new(CARD_ORDER, Wildcard::None))
+}
+
+pub fn part_two(hands: &[Hand]) -> Result<u32, RankingError> {
+    // J cards are now jokers, which act like whatever card makes the strongest hand
+    total_winnings(
+        hands,
+        &Ranking::new(CARD_ORDER, Wildcard::BestSubstitute('J')),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result =
+            part_one(&parse(&advent_of_code::template::read_file("examples", PUZZLE)).unwrap());
+        assert_eq!(result, Ok(6440));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result =
+            part_two(&parse(&advent_of_code::template::read_file("examples", PUZZLE)).unwrap());
+        assert_eq!(result, Ok(5905));
+    }
+
+    #[test]
+    fn test_hand_comparison() {
+        let ranking = Ranking::new(CARD_ORDER, Wildcard::None);
+        assert!(ranking.key("77888").unwrap() > ranking.key("77788").unwrap());
+    }
+}
@@ -0,0 +1,145 @@
+use advent_of_code::graph::{find_cycle, first_common_step, LabelledGraph};
+use std::collections::HashSet;
+
+use advent_of_code::parse::{sections, ParseError};
+use petgraph::graph::NodeIndex;
+
+advent_of_code::solution!(2023, 8, parse?);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Movement {
+    Left,
+    Right,
+}
+
+pub struct Network<'a> {
+    instructions: Vec<Movement>,
+    graph: LabelledGraph<&'a str, Movement>,
+}
+
+impl Network<'_> {
+    /// Follows the instruction at `index` from `node`, returning the next node and instruction index.
+    fn step(&self, (node, index): (NodeIndex, usize)) -> (NodeIndex, usize) {
+        // parse makes sure that every node can be left in both directions, so walks never stay on a node.
+        let next = self
+            .graph
+            .follow(node, &self.instructions[index])
+            .unwrap_or(node);
+        (next, (index + 1) % self.instructions.len())
+    }
+}
+
+pub fn parse(input: &str) -> Result<Network<'_>, ParseError> {
+    // Parse the input into instructions and a network of nodes with their left and right neighbours.
+    // Example:
+    // LLR
+    //
+    // AAA = (BBB, BBB)
+    // BBB = (AAA, ZZZ)
+    // ZZZ = (ZZZ, ZZZ)
+    let sections = sections(input);
+    let [instructions, nodes] = &sections[..] else {
+        return Err(ParseError {
+            line: 1,
+            column: 1,
+            message: "expected instructions and nodes separated by a blank line".into(),
+        });
+    };
+
+    let directions = instructions[0];
+    if directions.is_empty() {
+        return Err(directions.error("expected at least one direction"));
+    }
+
+    let instructions = directions
+        .char_indices()
+        .map(|(i, c)| match c {
+            'L' => Ok(Movement::Left),
+            'R' => Ok(Movement::Right),
+            _ => Err(ParseError {
+                line: directions.line(),
+                column: i + 1,
+                message: format!("invalid direction `{c}`"),
+            }),
+        })
+        .collect::<Result<_, _>>()?;
+
+    let records = nodes
+        .iter()
+        .map(|line| line.record())
+        .collect::<Result<Vec<_>, ParseError>>()?;
+
+    // a walk could not leave a node without a record of its own.
+    let keys: HashSet<&str> = records.iter().map(|record| record.key.as_str()).collect();
+    if let Some(target) = records
+        .iter()
+        .flat_map(|record| [record.left, record.right])
+        .find(|target| !keys.contains(target.as_str()))
+    {
+        return Err(target.error(format!("node `{}` has no record", target.as_str())));
+    }
+
+    let table = records.iter().map(|record| {
+        (
+            record.key.as_str(),
+            [
+                (Movement::Left, record.left.as_str()),
+                (Movement::Right, record.right.as_str()),
+            ],
+        )
+    });
+
+    Ok(Network {
+        instructions,
+        graph: LabelledGraph::from_table(table),
+    })
+}
+
+pub fn part_one(network: &Network) -> Option<u64> {
+    // Follow the instructions from AAA, cycling through them, and count the moves until ZZZ
+    let mut state = (network.graph.index(&"AAA")?, 0);
+    let end = network.graph.index(&"ZZZ")?;
+    let mut moves = 0;
+
+    while state.0 != end {
+        state = network.step(state);
+        moves += 1;
+    }
+
+    Some(moves)
+}
+
+pub fn part_two(network: &Network) -> Option<u64> {
+    // Walking all nodes ending in 'A' in lockstep until they all end in 'Z' takes too long.
+    // Instead, find the cycle of every walk and the steps at which it is on a node ending in 'Z',
+    // then combine them to the first step they have in common.
+    let walks: Vec<_> = network
+        .graph
+        .nodes()
+        .filter(|(_, label)| label.ends_with('A'))
+        .map(|(start, _)| {
+            find_cycle((start, 0), |&state| network.step(state))
+                .hits(|&(node, _)| network.graph.label(node).ends_with('Z'))
+        })
+        .collect();
+
+    first_common_step(&walks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    advent_of_code::test_examples!(parse?);
+
+    #[test]
+    fn rejects_nodes_without_record() {
+        let error = parse("LR\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)")
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 8: node `BBB` has no record"
+        );
+    }
+}
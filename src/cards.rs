@@ -0,0 +1,184 @@
+//! Ranking of poker-like hands with a configurable card order and wildcard rules.
+//!
+//! ```
+//! use advent_of_code::cards::{HandType, Ranking, Wildcard};
+//!
+//! let ranking = Ranking::new("23456789TJQKA", Wildcard::BestSubstitute('J'));
+//! assert_eq!(ranking.hand_type("KTJJT").unwrap(), HandType::FourOfAKind);
+//! assert!(ranking.key("QQQQ2").unwrap() > ranking.key("JKKK2").unwrap());
+//! ```
+use std::fmt::Display;
+
+/// The type of a hand, ordered from the weakest to the strongest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+/// How a wildcard, e.g. a joker, is treated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Wildcard {
+    /// There is no wildcard, every card only stands for itself.
+    #[default]
+    None,
+    /// The card counts as the lowest card when breaking ties, but does not change the hand type.
+    Low(char),
+    /// The card becomes whichever card makes the strongest hand type, and counts as the lowest card when breaking ties.
+    BestSubstitute(char),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RankingError {
+    UnknownCard(char),
+}
+
+impl std::error::Error for RankingError {}
+
+impl Display for RankingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RankingError::UnknownCard(card) => write!(f, "unknown card `{card}`"),
+        }
+    }
+}
+
+/// The sort key of a hand: its type, then the ranks of its cards in the order they were dealt.
+/// Compute it once per hand and sort by it, instead of scoring hands on every comparison.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HandKey {
+    pub hand_type: HandType,
+    pub ranks: Vec<u8>,
+}
+
+/// The rules to rank hands by.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ranking {
+    /// The cards from the lowest to the highest.
+    order: Vec<char>,
+    wildcard: Wildcard,
+}
+
+impl Ranking {
+    /// Creates a ranking with the cards of `order` listed from the lowest to the highest, e.g. `"23456789TJQKA"`.
+    pub fn new(order: &str, wildcard: Wildcard) -> Self {
+        Self {
+            order: order.chars().collect(),
+            wildcard,
+        }
+    }
+
+    /// The rank of a card for breaking ties: 0 for the wildcard, then from 1 for the lowest card of the order.
+    pub fn rank(&self, card: char) -> Result<u8, RankingError> {
+        if let Wildcard::Low(wildcard) | Wildcard::BestSubstitute(wildcard) = self.wildcard {
+            if card == wildcard {
+                return Ok(0);
+            }
+        }
+
+        let rank = self
+            .order
+            .iter()
+            .position(|&c| c == card)
+            .ok_or(RankingError::UnknownCard(card))?;
+
+        // Make room for wildcards below the lowest card.
+        Ok(rank as u8 + 1)
+    }
+
+    pub fn hand_type(&self, cards: &str) -> Result<HandType, RankingError> {
+        let wildcard = match self.wildcard {
+            Wildcard::BestSubstitute(wildcard) => Some(wildcard),
+            _ => None,
+        };
+
+        let mut counts = vec![0; self.order.len()];
+        let mut wildcards = 0;
+        for card in cards.chars() {
+            if Some(card) == wildcard {
+                wildcards += 1;
+            } else {
+                let index = self
+                    .order
+                    .iter()
+                    .position(|&c| c == card)
+                    .ok_or(RankingError::UnknownCard(card))?;
+                counts[index] += 1;
+            }
+        }
+
+        // Wildcards are best used as more of the most common card.
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        let first = counts.first().copied().unwrap_or_default() + wildcards;
+        let second = counts.get(1).copied().unwrap_or_default();
+
+        Ok(match (first, second) {
+            (5.., _) => HandType::FiveOfAKind,
+            (4, _) => HandType::FourOfAKind,
+            (3, 2..) => HandType::FullHouse,
+            (3, _) => HandType::ThreeOfAKind,
+            (2, 2..) => HandType::TwoPair,
+            (2, _) => HandType::OnePair,
+            _ => HandType::HighCard,
+        })
+    }
+
+    pub fn key(&self, cards: &str) -> Result<HandKey, RankingError> {
+        Ok(HandKey {
+            hand_type: self.hand_type(cards)?,
+            ranks: cards
+                .chars()
+                .map(|card| self.rank(card))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{HandType, Ranking, RankingError, Wildcard};
+
+    const ORDER: &str = "23456789TJQKA";
+
+    #[test]
+    fn finds_hand_types() {
+        let ranking = Ranking::new(ORDER, Wildcard::None);
+
+        assert_eq!(ranking.hand_type("32T3K"), Ok(HandType::OnePair));
+        assert_eq!(ranking.hand_type("KK677"), Ok(HandType::TwoPair));
+        assert_eq!(ranking.hand_type("T55J5"), Ok(HandType::ThreeOfAKind));
+        assert_eq!(ranking.hand_type("23332"), Ok(HandType::FullHouse));
+        assert_eq!(ranking.hand_type("23456"), Ok(HandType::HighCard));
+        assert_eq!(
+            ranking.hand_type("2345X"),
+            Err(RankingError::UnknownCard('X'))
+        );
+    }
+
+    #[test]
+    fn breaks_ties_by_card_order() {
+        let ranking = Ranking::new(ORDER, Wildcard::None);
+
+        assert!(ranking.key("77888").unwrap() > ranking.key("77788").unwrap());
+        assert!(ranking.key("KK677").unwrap() > ranking.key("KTJJT").unwrap());
+        assert_eq!(ranking.key("AAAAA"), ranking.key("AAAAA"));
+    }
+
+    #[test]
+    fn applies_wildcard_rules() {
+        let low = Ranking::new(ORDER, Wildcard::Low('J'));
+        assert_eq!(low.hand_type("KTJJT"), Ok(HandType::TwoPair));
+        assert!(low.key("2345J").unwrap() < low.key("23452").unwrap());
+
+        let best = Ranking::new(ORDER, Wildcard::BestSubstitute('J'));
+        assert_eq!(best.hand_type("KTJJT"), Ok(HandType::FourOfAKind));
+        assert_eq!(best.hand_type("JJJJJ"), Ok(HandType::FiveOfAKind));
+        assert_eq!(best.hand_type("2345J"), Ok(HandType::OnePair));
+        assert!(best.key("JKKK2").unwrap() < best.key("QQQQ2").unwrap());
+    }
+}
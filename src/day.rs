@@ -114,6 +114,175 @@ impl Iterator for AllDays {
 
 /* -------------------------------------------------------------------------- */
 
+/// An inclusive range of days, e.g. `1-5`, or a single day, e.g. `8`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DayRange {
+    start: Day,
+    end: Day,
+}
+
+impl DayRange {
+    /// Creates a [`DayRange`] from `start` to `end`, returns [`None`] if `end` comes before `start`.
+    pub fn new(start: Day, end: Day) -> Option<Self> {
+        (start <= end).then_some(Self { start, end })
+    }
+
+    /// The days from `start` to the 25th.
+    pub fn since(start: Day) -> Self {
+        Self {
+            start,
+            end: Day(25),
+        }
+    }
+
+    pub fn start(&self) -> Day {
+        self.start
+    }
+
+    pub fn end(&self) -> Day {
+        self.end
+    }
+
+    pub fn contains(&self, day: Day) -> bool {
+        self.start <= day && day <= self.end
+    }
+
+    pub fn days(&self) -> impl Iterator<Item = Day> {
+        // NOTE: both ends are valid days.
+        (self.start.0..=self.end.0).map(Day)
+    }
+}
+
+impl From<Day> for DayRange {
+    fn from(day: Day) -> Self {
+        Self {
+            start: day,
+            end: day,
+        }
+    }
+}
+
+impl FromStr for DayRange {
+    type Err = DayRangeFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        match s.split_once('-') {
+            Some((start, end)) => {
+                let start = start.trim().parse().map_err(|_| DayRangeFromStrError)?;
+                let end = end.trim().parse().map_err(|_| DayRangeFromStrError)?;
+                Self::new(start, end).ok_or(DayRangeFromStrError)
+            }
+            None => Ok(s.parse::<Day>().map_err(|_| DayRangeFromStrError)?.into()),
+        }
+    }
+}
+
+/// An error which can be returned when parsing a [`DayRange`] or a [`DaySet`].
+#[derive(Debug)]
+pub struct DayRangeFromStrError;
+
+impl Error for DayRangeFromStrError {}
+
+impl Display for DayRangeFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting days between 1 and 25 and ranges of them, e.g. `1-5,8`")
+    }
+}
+
+/// A set of days, parsed from a comma-separated list of days and ranges, e.g. `1-5,8`.
+///
+/// ```
+/// # use advent_of_code::{Day, DaySet};
+/// let days: DaySet = "1-5,8".parse().unwrap();
+/// assert!(days.contains(Day::new(8).unwrap()));
+/// assert_eq!(days.len(), 6);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DaySet {
+    /// Bit `n` is set if day `n` is part of the set.
+    bits: u32,
+}
+
+impl DaySet {
+    /// The empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The set of all 25 days.
+    pub fn all() -> Self {
+        all_days().collect()
+    }
+
+    pub fn insert(&mut self, day: Day) {
+        self.bits |= 1 << day.0;
+    }
+
+    pub fn contains(&self, day: Day) -> bool {
+        self.bits & (1 << day.0) != 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+
+    /// The days that are in this set but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        Self {
+            bits: self.bits & !other.bits,
+        }
+    }
+
+    /// The days that are in both sets.
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self {
+            bits: self.bits & other.bits,
+        }
+    }
+
+    /// The days of the set in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = Day> + '_ {
+        all_days().filter(|&day| self.contains(day))
+    }
+}
+
+impl From<DayRange> for DaySet {
+    fn from(range: DayRange) -> Self {
+        range.days().collect()
+    }
+}
+
+impl FromIterator<Day> for DaySet {
+    fn from_iter<T: IntoIterator<Item = Day>>(iter: T) -> Self {
+        let mut set = Self::new();
+        for day in iter {
+            set.insert(day);
+        }
+        set
+    }
+}
+
+impl FromStr for DaySet {
+    type Err = DayRangeFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut set = Self::new();
+        for range in s.split(',') {
+            for day in range.parse::<DayRange>()?.days() {
+                set.insert(day);
+            }
+        }
+        Ok(set)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 /// Creates a [`Day`] value in a const context.
 #[macro_export]
 macro_rules! day {
@@ -134,7 +303,7 @@ macro_rules! day {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{all_days, Day};
+    use super::{all_days, Day, DayRange, DaySet};
 
     #[test]
     fn all_days_iterator() {
@@ -167,6 +336,33 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn parses_day_ranges() {
+        assert_eq!("8".parse::<DayRange>().ok(), Some(Day(8).into()));
+        assert_eq!(
+            "1-5".parse::<DayRange>().ok(),
+            DayRange::new(Day(1), Day(5))
+        );
+        assert!("5-1".parse::<DayRange>().is_err());
+        assert!("0-3".parse::<DayRange>().is_err());
+        assert!("1-".parse::<DayRange>().is_err());
+        assert!(DayRange::since(Day(20)).contains(Day(25)));
+    }
+
+    #[test]
+    fn parses_day_sets() {
+        let days: DaySet = "1-3, 8,2".parse().unwrap();
+        assert_eq!(
+            days.iter().collect::<Vec<_>>(),
+            [Day(1), Day(2), Day(3), Day(8)]
+        );
+
+        let skip: DaySet = "2".parse().unwrap();
+        assert_eq!(days.difference(&skip).len(), 3);
+        assert_eq!(DaySet::all().len(), 25);
+        assert!("1,,2".parse::<DaySet>().is_err());
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -0,0 +1,260 @@
+//! Labelled directed graphs and cycle detection, for puzzles that walk a network until something lines up.
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use petgraph::graph::{DiGraph, NodeIndex};
+use petgraph::visit::EdgeRef;
+
+use crate::math::combine_congruences;
+
+/// A directed graph whose nodes are looked up by their label, e.g. `"AAA"`.
+/// Edges carry a label too, e.g. the direction to take, so a node can be followed along an edge label.
+/// Use [`LabelledGraph::graph`] to run the algorithms of `petgraph` on it.
+#[derive(Debug, Clone)]
+pub struct LabelledGraph<N, E> {
+    graph: DiGraph<N, E>,
+    indices: HashMap<N, NodeIndex>,
+}
+
+impl<N, E> Default for LabelledGraph<N, E> {
+    fn default() -> Self {
+        Self {
+            graph: DiGraph::default(),
+            indices: HashMap::new(),
+        }
+    }
+}
+
+impl<N: Clone + Eq + Hash, E> LabelledGraph<N, E> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a graph from a table of nodes and their outgoing edges,
+    /// e.g. `AAA = (BBB, CCC)` is `("AAA", [('L', "BBB"), ('R', "CCC")])`.
+    pub fn from_table<R>(table: impl IntoIterator<Item = (N, R)>) -> Self
+    where
+        R: IntoIterator<Item = (E, N)>,
+    {
+        let mut graph = Self::new();
+        for (from, edges) in table {
+            for (edge, to) in edges {
+                graph.add_edge(from.clone(), to, edge);
+            }
+        }
+        graph
+    }
+
+    /// Adds a node, or returns the index of the node if it already exists.
+    pub fn add_node(&mut self, label: N) -> NodeIndex {
+        if let Some(&index) = self.indices.get(&label) {
+            return index;
+        }
+        let index = self.graph.add_node(label.clone());
+        self.indices.insert(label, index);
+        index
+    }
+
+    /// Adds an edge, adding its nodes if they do not exist yet.
+    pub fn add_edge(&mut self, from: N, to: N, edge: E) {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+        self.graph.add_edge(from, to, edge);
+    }
+
+    pub fn index(&self, label: &N) -> Option<NodeIndex> {
+        self.indices.get(label).copied()
+    }
+
+    pub fn label(&self, index: NodeIndex) -> &N {
+        &self.graph[index]
+    }
+
+    /// All nodes with their labels, in the order they were added.
+    pub fn nodes(&self) -> impl Iterator<Item = (NodeIndex, &N)> {
+        self.graph
+            .node_indices()
+            .map(|index| (index, &self.graph[index]))
+    }
+
+    /// The node reached by following the outgoing edge labelled `edge`.
+    pub fn follow(&self, node: NodeIndex, edge: &E) -> Option<NodeIndex>
+    where
+        E: PartialEq,
+    {
+        self.graph
+            .edges(node)
+            .find(|e| e.weight() == edge)
+            .map(|e| e.target())
+    }
+
+    pub fn graph(&self) -> &DiGraph<N, E> {
+        &self.graph
+    }
+}
+
+/// The states of a walk up to the point where it starts repeating itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<S> {
+    /// The states from the start until just before the first repeated state, indexed by step.
+    pub states: Vec<S>,
+    /// The number of steps before the cycle is entered.
+    pub offset: usize,
+    /// The number of steps of one round of the cycle.
+    pub length: usize,
+}
+
+impl<S> Cycle<S> {
+    /// The state after any number of steps.
+    pub fn state_at(&self, step: u64) -> &S {
+        &self.states[self.index_of(step)]
+    }
+
+    /// The steps, before the walk repeats, whose state matches `predicate`.
+    pub fn hits(&self, predicate: impl Fn(&S) -> bool) -> Hits {
+        Hits {
+            offset: self.offset,
+            length: self.length,
+            steps: (0..self.states.len())
+                .filter(|&step| predicate(&self.states[step]))
+                .collect(),
+        }
+    }
+
+    fn index_of(&self, step: u64) -> usize {
+        let offset = self.offset as u64;
+        if step < offset {
+            step as usize
+        } else {
+            (offset + (step - offset) % self.length as u64) as usize
+        }
+    }
+}
+
+/// Follows `next` from `start` until a state repeats.
+/// The state must contain everything that decides the next step, e.g. the node together with the index of the next instruction.
+pub fn find_cycle<S: Clone + Eq + Hash>(start: S, mut next: impl FnMut(&S) -> S) -> Cycle<S> {
+    let mut seen = HashMap::new();
+    let mut states = vec![];
+    let mut state = start;
+
+    loop {
+        if let Some(&offset) = seen.get(&state) {
+            return Cycle {
+                length: states.len() - offset,
+                offset,
+                states,
+            };
+        }
+        seen.insert(state.clone(), states.len());
+        let following = next(&state);
+        states.push(state);
+        state = following;
+    }
+}
+
+/// The steps at which a cycling walk is in a state of interest, see [`Cycle::hits`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hits {
+    pub offset: usize,
+    pub length: usize,
+    /// The matching steps before the walk repeats, i.e. below `offset + length`.
+    pub steps: Vec<usize>,
+}
+
+impl Hits {
+    pub fn contains(&self, step: u64) -> bool {
+        let offset = self.offset as u64;
+        let index = if step < offset {
+            step
+        } else {
+            offset + (step - offset) % self.length as u64
+        };
+        self.steps.binary_search(&(index as usize)).is_ok()
+    }
+}
+
+/// The first step at which all walks are in a state of interest at the same time.
+/// Steps inside the cycles are combined with the chinese remainder theorem, so the walks never have to be stepped in lockstep.
+pub fn first_common_step(walks: &[Hits]) -> Option<u64> {
+    let max_offset = walks.iter().map(|hits| hits.offset).max()? as u64;
+
+    // Before every walk has entered its cycle, the steps cannot be combined.
+    if let Some(step) = (0..max_offset).find(|&step| walks.iter().all(|h| h.contains(step))) {
+        return Some(step);
+    }
+
+    let mut congruences = vec![(0, 1)];
+    for hits in walks {
+        let residues: Vec<u64> = hits
+            .steps
+            .iter()
+            .filter(|&&step| step >= hits.offset)
+            .map(|&step| (step % hits.length) as u64)
+            .collect();
+
+        congruences = congruences
+            .iter()
+            .flat_map(|&congruence| {
+                residues
+                    .iter()
+                    .filter_map(move |&r| combine_congruences(congruence, (r, hits.length as u64)))
+            })
+            .collect();
+    }
+
+    congruences
+        .into_iter()
+        .map(|(r, m)| {
+            if r >= max_offset {
+                r
+            } else {
+                r + (max_offset - r).div_ceil(m) * m
+            }
+        })
+        .min()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{find_cycle, first_common_step, LabelledGraph};
+
+    #[test]
+    fn follows_labelled_edges() {
+        let graph = LabelledGraph::from_table([
+            ("AAA", [('L', "BBB"), ('R', "CCC")]),
+            ("BBB", [('L', "AAA"), ('R', "AAA")]),
+        ]);
+
+        let aaa = graph.index(&"AAA").unwrap();
+        let ccc = graph.follow(aaa, &'R').unwrap();
+        assert_eq!(*graph.label(ccc), "CCC");
+        assert_eq!(graph.follow(ccc, &'L'), None);
+        assert_eq!(graph.nodes().count(), 3);
+    }
+
+    #[test]
+    fn finds_cycles() {
+        // 0 -> 1 -> 2 -> 3 -> 4 -> 2
+        let cycle = find_cycle(0, |&n| if n == 4 { 2 } else { n + 1 });
+
+        assert_eq!((cycle.offset, cycle.length), (2, 3));
+        assert_eq!(*cycle.state_at(1), 1);
+        assert_eq!(*cycle.state_at(7), 4);
+        assert_eq!(*cycle.state_at(8), 2);
+    }
+
+    #[test]
+    fn finds_first_common_step() {
+        // Hits every 3rd and every 4th step, starting from step 1.
+        let a = find_cycle(0, |&n| (n + 1) % 3).hits(|&n| n == 1);
+        let b = find_cycle(0, |&n| (n + 1) % 4).hits(|&n| n == 1);
+        assert_eq!(first_common_step(&[a.clone(), b]), Some(1));
+
+        let c = find_cycle(0, |&n| (n + 1) % 4).hits(|&n| n == 3);
+        assert_eq!(first_common_step(&[a.clone(), c]), Some(7));
+
+        let never = find_cycle(0, |&n| (n + 1) % 6).hits(|&n| n == 2);
+        assert_eq!(first_common_step(&[a, never]), None);
+    }
+}
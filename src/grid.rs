@@ -0,0 +1,411 @@
+//! A two-dimensional grid of cells, as found in many puzzle inputs.
+//! Coordinates start at the top left corner, `x` grows to the right and `y` grows downwards.
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+/// The position of a cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Point {
+    pub const fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// The offsets of the 4 orthogonal neighbours: up, right, down, left.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// The offsets of the 4 diagonal neighbours, clockwise from the top left.
+pub const DIAGONAL: [(isize, isize); 4] = [(-1, -1), (1, -1), (1, 1), (-1, 1)];
+
+/// The offsets of all 8 neighbours, clockwise from the top left.
+pub const ALL: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
+/// An error which can be returned when parsing a [`Grid`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum GridError {
+    /// A row does not have as many cells as the first row.
+    Ragged {
+        row: usize,
+        expected: usize,
+        actual: usize,
+    },
+}
+
+impl std::error::Error for GridError {}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridError::Ragged {
+                row,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "row {row} has {actual} cells, expecting {expected} like the first row"
+            ),
+        }
+    }
+}
+
+/// A horizontal run of adjacent cells, e.g. the digits of a multi-digit number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Run {
+    pub start: Point,
+    pub len: usize,
+}
+
+impl Run {
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let Point { x, y } = self.start;
+        (x..x + self.len).map(move |x| Point::new(x, y))
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.y == self.start.y && (self.start.x..self.start.x + self.len).contains(&point.x)
+    }
+}
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Parses every line of `s` into a row, converting each character with `f`.
+    pub fn parse_with(s: &str, mut f: impl FnMut(char) -> T) -> Result<Self, GridError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for (row, line) in s.lines().enumerate() {
+            let before = cells.len();
+            cells.extend(line.chars().map(&mut f));
+            let actual = cells.len() - before;
+
+            match width {
+                None => width = Some(actual),
+                Some(expected) if expected != actual => {
+                    return Err(GridError::Ragged {
+                        row,
+                        expected,
+                        actual,
+                    })
+                }
+                Some(_) => {}
+            }
+
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap_or_default(),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.y * self.width + point.x])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if self.contains(point) {
+            Some(&mut self.cells[point.y * self.width + point.x])
+        } else {
+            None
+        }
+    }
+
+    /// Moves `point` by `(dx, dy)`, returns [`None`] if that leaves the grid.
+    pub fn offset(&self, point: Point, (dx, dy): (isize, isize)) -> Option<Point> {
+        let moved = Point::new(
+            point.x.checked_add_signed(dx)?,
+            point.y.checked_add_signed(dy)?,
+        );
+        self.contains(moved).then_some(moved)
+    }
+
+    /// The orthogonal neighbours of `point` inside the grid.
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        ORTHOGONAL
+            .into_iter()
+            .filter_map(move |offset| self.offset(point, offset))
+    }
+
+    /// The orthogonal and diagonal neighbours of `point` inside the grid.
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        ALL.into_iter()
+            .filter_map(move |offset| self.offset(point, offset))
+    }
+
+    /// Every point of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| Point::new(i % width, i / width))
+    }
+
+    /// Every cell of the grid together with its point, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// The cells of row `y`, from left to right.
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &T> {
+        let row = if y < self.height {
+            &self.cells[y * self.width..(y + 1) * self.width]
+        } else {
+            &[]
+        };
+        row.iter()
+    }
+
+    /// The cells of column `x`, from top to bottom.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let height = if x < self.width { self.height } else { 0 };
+        (0..height).map(move |y| &self.cells[y * self.width + x])
+    }
+
+    /// The points from `start` in the direction of `(dx, dy)` until the edge of the grid, excluding `start`.
+    /// Use this to scan rows, columns and diagonals, e.g. `grid.ray(start, (1, 1))`.
+    pub fn ray(&self, start: Point, direction: (isize, isize)) -> impl Iterator<Item = Point> + '_ {
+        std::iter::successors(self.offset(start, direction), move |&point| {
+            self.offset(point, direction)
+        })
+    }
+
+    /// The run of adjacent cells matching `predicate` in the row of `point`, if `point` matches.
+    pub fn run_at(&self, point: Point, predicate: impl Fn(&T) -> bool) -> Option<Run> {
+        if !predicate(self.get(point)?) {
+            return None;
+        }
+
+        let row: Vec<&T> = self.row(point.y).collect();
+        let start = row[..point.x]
+            .iter()
+            .rposition(|cell| !predicate(cell))
+            .map_or(0, |x| x + 1);
+        let end = row[point.x..]
+            .iter()
+            .position(|cell| !predicate(cell))
+            .map_or(self.width, |x| point.x + x);
+
+        Some(Run {
+            start: Point::new(start, point.y),
+            len: end - start,
+        })
+    }
+
+    /// All maximal runs of adjacent cells matching `predicate`, row by row.
+    pub fn runs(&self, predicate: impl Fn(&T) -> bool) -> Vec<Run> {
+        let mut runs = vec![];
+
+        for y in 0..self.height {
+            let mut start: Option<usize> = None;
+
+            for (x, cell) in self.row(y).enumerate() {
+                match (predicate(cell), start) {
+                    (true, None) => start = Some(x),
+                    (false, Some(s)) => {
+                        runs.push(Run {
+                            start: Point::new(s, y),
+                            len: x - s,
+                        });
+                        start = None;
+                    }
+                    _ => {}
+                }
+            }
+
+            if let Some(s) = start {
+                runs.push(Run {
+                    start: Point::new(s, y),
+                    len: self.width - s,
+                });
+            }
+        }
+
+        runs
+    }
+
+    /// Converts every cell with `f`, e.g. to parse digits.
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Draws the grid with one character per cell, for debugging.
+    pub fn render(&self, f: impl Fn(Point, &T) -> char) -> String {
+        let mut s = String::with_capacity((self.width + 1) * self.height);
+        for (point, cell) in self.iter() {
+            s.push(f(point, cell));
+            if point.x + 1 == self.width {
+                s.push('\n');
+            }
+        }
+        s
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, |c| c)
+    }
+}
+
+impl Display for Grid<char> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.render(|_, &c| c))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        match self.get(point) {
+            Some(cell) => cell,
+            None => panic!(
+                "point {point} is outside of the {}x{} grid",
+                self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(point) {
+            Some(cell) => cell,
+            None => panic!("point {point} is outside of the {width}x{height} grid"),
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Grid, GridError, Point, Run};
+
+    fn get_mock_grid() -> Grid<char> {
+        "467..114\n...*....\n..35..63\n".parse().unwrap()
+    }
+
+    #[test]
+    fn parses_grid() {
+        let grid = get_mock_grid();
+        assert_eq!((grid.width(), grid.height()), (8, 3));
+        assert_eq!(grid[Point::new(3, 1)], '*');
+        assert_eq!(grid.get(Point::new(8, 0)), None);
+        assert_eq!(grid.to_string(), "467..114\n...*....\n..35..63\n");
+    }
+
+    #[test]
+    fn rejects_ragged_rows() {
+        assert_eq!(
+            "abc\nab".parse::<Grid<char>>(),
+            Err(GridError::Ragged {
+                row: 1,
+                expected: 3,
+                actual: 2
+            })
+        );
+    }
+
+    #[test]
+    fn finds_neighbors_inside_bounds() {
+        let grid = get_mock_grid();
+        assert_eq!(grid.neighbors4(Point::new(0, 0)).count(), 2);
+        assert_eq!(grid.neighbors8(Point::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbors8(Point::new(3, 1)).count(), 8);
+        assert_eq!(grid.offset(Point::new(0, 0), (-1, 0)), None);
+    }
+
+    #[test]
+    fn scans_rows_columns_and_diagonals() {
+        let grid = get_mock_grid();
+        assert_eq!(grid.row(1).collect::<String>(), "...*....");
+        assert_eq!(grid.column(2).collect::<String>(), "7.3");
+        assert_eq!(
+            grid.ray(Point::new(0, 0), (1, 1)).collect::<Vec<_>>(),
+            vec![Point::new(1, 1), Point::new(2, 2)]
+        );
+    }
+
+    #[test]
+    fn extracts_runs() {
+        let grid = get_mock_grid();
+        let runs = grid.runs(char::is_ascii_digit);
+
+        assert_eq!(runs.len(), 4);
+        assert_eq!(
+            runs[1],
+            Run {
+                start: Point::new(5, 0),
+                len: 3
+            }
+        );
+        assert_eq!(
+            grid.run_at(Point::new(3, 2), char::is_ascii_digit),
+            Some(Run {
+                start: Point::new(2, 2),
+                len: 2
+            })
+        );
+        assert_eq!(grid.run_at(Point::new(3, 1), char::is_ascii_digit), None);
+    }
+}
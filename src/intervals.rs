@@ -0,0 +1,278 @@
+//! Sets of integers stored as sorted ranges, and piecewise maps that shift whole ranges at once.
+//! All ranges are half-open, like [`std::ops::Range`].
+use std::ops::Range;
+
+/// A set of integers, stored as sorted, disjoint and non-adjacent ranges.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    ranges: Vec<Range<i64>>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds all integers of `range`, merging it with the ranges it overlaps or touches.
+    pub fn insert(&mut self, range: Range<i64>) {
+        if range.is_empty() {
+            return;
+        }
+
+        let first = self.ranges.partition_point(|r| r.end < range.start);
+        let last = self.ranges.partition_point(|r| r.start <= range.end);
+
+        let merged = if first < last {
+            self.ranges[first].start.min(range.start)..self.ranges[last - 1].end.max(range.end)
+        } else {
+            range
+        };
+
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    /// The sorted, disjoint ranges of the set.
+    pub fn ranges(&self) -> &[Range<i64>] {
+        &self.ranges
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let i = self.ranges.partition_point(|r| r.end <= value);
+        self.ranges.get(i).is_some_and(|r| r.contains(&value))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The number of integers in the set.
+    pub fn len(&self) -> u64 {
+        self.ranges.iter().map(|r| r.start.abs_diff(r.end)).sum()
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.ranges.first().map(|r| r.start)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.ranges.last().map(|r| r.end - 1)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for range in &other.ranges {
+            union.insert(range.clone());
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (&self.ranges[i], &other.ranges[j]);
+            let overlap = a.start.max(b.start)..a.end.min(b.end);
+            if !overlap.is_empty() {
+                ranges.push(overlap);
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// The integers of `self` that are not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let mut others = other.ranges.iter().peekable();
+
+        for range in &self.ranges {
+            let mut start = range.start;
+
+            while let Some(o) = others.peek() {
+                if o.end <= start {
+                    others.next();
+                    continue;
+                }
+                if o.start >= range.end {
+                    break;
+                }
+                if o.start > start {
+                    ranges.push(start..o.start);
+                }
+                start = o.end;
+                if o.end > range.end {
+                    break;
+                }
+                others.next();
+            }
+
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+
+        Self { ranges }
+    }
+}
+
+impl FromIterator<Range<i64>> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Range<i64>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+/// A map that adds a constant offset to all integers of a range, and leaves integers outside of its ranges unchanged.
+/// Puzzles often describe these as lines of `destination source length`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PiecewiseMap {
+    /// Sorted, disjoint source ranges with the offset added to them.
+    pieces: Vec<(Range<i64>, i64)>,
+}
+
+impl PiecewiseMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maps `source` to the range of the same length starting at `destination`.
+    /// Integers already covered by an earlier piece keep their mapping.
+    pub fn insert(&mut self, source: Range<i64>, destination: i64) {
+        let offset = destination - source.start;
+        let covered: IntervalSet = self.pieces.iter().map(|(r, _)| r.clone()).collect();
+        let uncovered = IntervalSet::from_iter([source]).difference(&covered);
+
+        for range in uncovered.ranges {
+            let i = self.pieces.partition_point(|(r, _)| r.start < range.start);
+            self.pieces.insert(i, (range, offset));
+        }
+    }
+
+    pub fn get(&self, value: i64) -> i64 {
+        let i = self.pieces.partition_point(|(r, _)| r.end <= value);
+        match self.pieces.get(i) {
+            Some((range, offset)) if range.contains(&value) => value + offset,
+            _ => value,
+        }
+    }
+
+    /// Maps every integer of `set`, splitting its ranges where the pieces start and end.
+    pub fn map_set(&self, set: &IntervalSet) -> IntervalSet {
+        let mut mapped = IntervalSet::new();
+
+        for range in set.ranges() {
+            for (segment, offset) in self.segments() {
+                let overlap = segment.start.max(range.start)..segment.end.min(range.end);
+                if !overlap.is_empty() {
+                    mapped.insert(overlap.start + offset..overlap.end + offset);
+                }
+            }
+        }
+
+        mapped
+    }
+
+    /// The map that applies `self` and then `next`, so that `a.then(&b).get(x) == b.get(a.get(x))`.
+    pub fn then(&self, next: &Self) -> Self {
+        let mut pieces = vec![];
+
+        for (segment, offset) in self.segments() {
+            let image = segment.start + offset..segment.end + offset;
+
+            for (next_segment, next_offset) in next.segments() {
+                let overlap = next_segment.start.max(image.start)..next_segment.end.min(image.end);
+                let combined = offset + next_offset;
+                if !overlap.is_empty() && combined != 0 {
+                    pieces.push((overlap.start - offset..overlap.end - offset, combined));
+                }
+            }
+        }
+
+        pieces.sort_by_key(|(r, _)| r.start);
+        Self { pieces }
+    }
+
+    /// The pieces together with the unmapped gaps between them, which have an offset of 0.
+    fn segments(&self) -> Vec<(Range<i64>, i64)> {
+        let mut segments = vec![];
+        let mut start = i64::MIN;
+
+        for (range, offset) in &self.pieces {
+            if start < range.start {
+                segments.push((start..range.start, 0));
+            }
+            segments.push((range.clone(), *offset));
+            start = range.end;
+        }
+
+        if start < i64::MAX {
+            segments.push((start..i64::MAX, 0));
+        }
+
+        segments
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{IntervalSet, PiecewiseMap};
+
+    #[test]
+    fn merges_ranges() {
+        let set: IntervalSet = [5..8, 1..3, 3..4, 10..12, 7..10].into_iter().collect();
+
+        assert_eq!(set.ranges(), &[1..4, 5..12]);
+        assert_eq!(set.len(), 10);
+        assert!(set.contains(3));
+        assert!(!set.contains(4));
+        assert_eq!((set.min(), set.max()), (Some(1), Some(11)));
+    }
+
+    #[test]
+    fn combines_sets() {
+        let a: IntervalSet = [0..10, 20..30].into_iter().collect();
+        let b: IntervalSet = [5..25].into_iter().collect();
+
+        assert_eq!(a.union(&b).ranges(), &[0..30]);
+        assert_eq!(a.intersection(&b).ranges(), &[5..10, 20..25]);
+        assert_eq!(a.difference(&b).ranges(), &[0..5, 25..30]);
+        assert_eq!(b.difference(&a).ranges(), &[10..20]);
+    }
+
+    #[test]
+    fn maps_values_and_ranges() {
+        let mut map = PiecewiseMap::new();
+        map.insert(98..100, 50);
+        map.insert(50..98, 52);
+
+        assert_eq!(map.get(79), 81);
+        assert_eq!(map.get(98), 50);
+        assert_eq!(map.get(10), 10);
+
+        let seeds: IntervalSet = [95..105].into_iter().collect();
+        assert_eq!(map.map_set(&seeds).ranges(), &[50..52, 97..105]);
+    }
+
+    #[test]
+    fn composes_maps() {
+        let mut a = PiecewiseMap::new();
+        a.insert(0..10, 100);
+        let mut b = PiecewiseMap::new();
+        b.insert(105..200, 0);
+        b.insert(5..10, 20);
+
+        let composed = a.then(&b);
+        for x in -5..120 {
+            assert_eq!(composed.get(x), b.get(a.get(x)), "{x}");
+        }
+    }
+}
@@ -1,4 +1,14 @@
+pub mod cards;
 mod day;
+pub mod graph;
+pub mod grid;
+pub mod intervals;
+pub mod math;
+pub mod parse;
+mod puzzle;
 pub mod template;
+mod year;
 
 pub use day::*;
+pub use puzzle::*;
+pub use year::*;
@@ -1,56 +1,146 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve};
+use advent_of_code::template::commands::{all, bench_diff, download, read, scaffold, solve, watch};
 use args::{parse, AppArguments};
 
+/// Solutions in `src/bin`, collected by the build script.
+mod solutions {
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+}
+
 mod args {
-    use std::process;
+    use std::{env, process};
 
-    use advent_of_code::Day;
+    use advent_of_code::template::commands::all::AllOptions;
+    use advent_of_code::template::commands::scaffold::ScaffoldOptions;
+    use advent_of_code::template::input::InputSource;
+    use advent_of_code::template::parse_duration;
+    use advent_of_code::template::runner::{OutputFormat, RunOptions};
+    use advent_of_code::template::trace;
+    use advent_of_code::{DaySet, PuzzleId, Year};
 
     pub enum AppArguments {
         Download {
-            day: Day,
+            puzzle: PuzzleId,
         },
         Read {
-            day: Day,
+            puzzle: PuzzleId,
         },
         Scaffold {
-            day: Day,
+            puzzle: PuzzleId,
+            options: ScaffoldOptions,
         },
         Solve {
-            day: Day,
-            release: bool,
-            time: bool,
-            submit: Option<u8>,
+            puzzle: PuzzleId,
+            options: RunOptions,
+        },
+        Watch {
+            puzzle: PuzzleId,
         },
         All {
-            release: bool,
-            time: bool,
+            year: Year,
+            options: RunOptions,
+            all_options: AllOptions,
+        },
+        BenchDiff {
+            year: Year,
+            baseline: Option<String>,
+            /// The relative slowdown that is reported as a regression, e.g. `0.1` for 10%.
+            threshold: f64,
         },
     }
 
+    /// Reads the `--year` option, falling back to the `AOC_YEAR` environment variable.
+    fn parse_year(args: &mut pico_args::Arguments) -> Result<Year, Box<dyn std::error::Error>> {
+        match args.opt_value_from_str("--year")? {
+            Some(year) => Ok(year),
+            None => Year::from_env()
+                .ok_or_else(|| "no year specified, pass `--year` or set `AOC_YEAR`.".into()),
+        }
+    }
+
+    fn parse_puzzle(
+        args: &mut pico_args::Arguments,
+    ) -> Result<PuzzleId, Box<dyn std::error::Error>> {
+        let year = parse_year(args)?;
+        Ok(PuzzleId::new(year, args.free_from_str()?))
+    }
+
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
-        let mut args = pico_args::Arguments::from_env();
+        let mut raw_args: Vec<_> = env::args_os().skip(1).collect();
+        let mut trace = trace::take_arg(&mut raw_args);
+        let mut args = pico_args::Arguments::from_vec(raw_args);
 
         let app_args = match args.subcommand()?.as_deref() {
             Some("all") => AppArguments::All {
-                release: args.contains("--release"),
-                time: args.contains("--time"),
+                year: parse_year(&mut args)?,
+                options: RunOptions {
+                    time: args.contains("--time"),
+                    submit: None,
+                    format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                    bench_time: args.opt_value_from_fn("--bench-time", parse_duration)?,
+                    input: InputSource::Puzzle,
+                    trace: None,
+                },
+                all_options: AllOptions {
+                    check: args.contains("--check"),
+                    timeout: args.opt_value_from_fn("--timeout", parse_duration)?,
+                    jobs: args.opt_value_from_str("--jobs")?.unwrap_or(1),
+                    days: args
+                        .opt_value_from_str("--days")?
+                        .unwrap_or_else(DaySet::all),
+                    skip: args.opt_value_from_str("--skip")?.unwrap_or_default(),
+                    since: args.opt_value_from_str("--since")?,
+                    only_solved: args.contains("--only-solved"),
+                },
+            },
+            Some("bench-diff") => AppArguments::BenchDiff {
+                year: parse_year(&mut args)?,
+                baseline: args.opt_value_from_str("--baseline")?,
+                threshold: args
+                    .opt_value_from_str::<_, f64>("--threshold")?
+                    .unwrap_or(10.0)
+                    / 100.0,
             },
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                puzzle: parse_puzzle(&mut args)?,
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                puzzle: parse_puzzle(&mut args)?,
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
-            },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                time: args.contains("--time"),
+                puzzle: parse_puzzle(&mut args)?,
+                options: ScaffoldOptions {
+                    examples: args.contains("--examples"),
+                    answer_type: args.opt_value_from_str("--type")?.unwrap_or_default(),
+                    force: args.contains("--force"),
+                    dry_run: args.contains("--dry-run"),
+                },
             },
+            Some("solve") => {
+                let time = args.contains("--time");
+                let watch = args.contains("--watch");
+                let puzzle = parse_puzzle(&mut args)?;
+                let options = RunOptions {
+                    submit: args.opt_value_from_str("--submit")?,
+                    time,
+                    format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                    bench_time: args.opt_value_from_fn("--bench-time", parse_duration)?,
+                    input: InputSource::parse(&mut args)?,
+                    trace: trace::check(trace.take(), time)?,
+                };
+
+                if !watch {
+                    AppArguments::Solve { puzzle, options }
+                } else if options.time
+                    || options.submit.is_some()
+                    || options.trace.is_some()
+                    || options.format != OutputFormat::Text
+                    || options.input != InputSource::Puzzle
+                {
+                    return Err("`--watch` runs the examples and the puzzle input, it only supports the `--year` option.".into());
+                } else {
+                    AppArguments::Watch { puzzle }
+                }
+            }
             Some(x) => {
                 eprintln!("Unknown command: {x}");
                 process::exit(1);
@@ -61,6 +151,10 @@ mod args {
             }
         };
 
+        if trace.is_some() {
+            return Err("`--trace` is only supported by `solve`.".into());
+        }
+
         let remaining = args.finish();
         if !remaining.is_empty() {
             eprintln!("Warning: unknown argument(s): {remaining:?}.");
@@ -77,16 +171,23 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release, time } => all::handle(release, time),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold { day } => scaffold::handle(day),
-            AppArguments::Solve {
-                day,
-                release,
-                time,
-                submit,
-            } => solve::handle(day, release, time, submit),
+            AppArguments::All {
+                year,
+                options,
+                all_options,
+            } => all::handle(&solutions::REGISTRY, year, &options, &all_options),
+            AppArguments::BenchDiff {
+                year,
+                baseline,
+                threshold,
+            } => bench_diff::handle(year, baseline, threshold),
+            AppArguments::Download { puzzle } => download::handle(puzzle),
+            AppArguments::Read { puzzle } => read::handle(puzzle),
+            AppArguments::Scaffold { puzzle, options } => scaffold::handle(puzzle, &options),
+            AppArguments::Solve { puzzle, options } => {
+                solve::handle(&solutions::REGISTRY, puzzle, &options)
+            }
+            AppArguments::Watch { puzzle } => watch::handle(puzzle),
         },
     };
 }
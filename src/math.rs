@@ -0,0 +1,161 @@
+//! Number theory helpers. Functions whose result can overflow return [`None`] instead.
+use std::ops::RangeInclusive;
+
+pub fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// The least common multiple, or [`None`] if it overflows.
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// The least common multiple of all numbers, e.g. the first step at which several cycles line up.
+pub fn lcm_all(numbers: impl IntoIterator<Item = u64>) -> Option<u64> {
+    numbers.into_iter().try_fold(1, lcm)
+}
+
+/// Combines `x ≡ r1 (mod m1)` and `x ≡ r2 (mod m2)` into `x ≡ r (mod lcm(m1, m2))`.
+/// The moduli do not need to be coprime, returns [`None`] if there is no solution, it overflows, or a modulus is 0.
+pub fn combine_congruences((r1, m1): (u64, u64), (r2, m2): (u64, u64)) -> Option<(u64, u64)> {
+    if m1 == 0 || m2 == 0 {
+        return None;
+    }
+
+    let g = gcd(m1, m2);
+    let (r1, r2) = (r1 % m1, r2 % m2);
+    if r1.abs_diff(r2) % g != 0 {
+        return None;
+    }
+
+    let m = lcm(m1, m2)?;
+    // Solve m1 * k ≡ r2 - r1 (mod m2), then x = r1 + m1 * k.
+    let (m1, m2, m, g) = (m1 as i128, m2 as i128, m as i128, g as i128);
+    let diff = (r2 as i128 - r1 as i128) / g;
+    let inverse = mod_inverse(m1 / g, m2 / g)?;
+    let k = (diff * inverse).rem_euclid(m2 / g);
+    let x = (r1 as i128 + m1 * k).rem_euclid(m);
+
+    Some((x as u64, m as u64))
+}
+
+/// Solves a system of congruences `x ≡ r (mod m)` with the generalized chinese remainder theorem.
+/// Returns the smallest solution together with the modulus of all solutions, e.g. `(2, 3), (3, 5)` is `(8, 15)`.
+pub fn crt(congruences: impl IntoIterator<Item = (u64, u64)>) -> Option<(u64, u64)> {
+    congruences
+        .into_iter()
+        .try_fold((0, 1), combine_congruences)
+}
+
+/// The inverse of `a` modulo `m`, if `a` and `m` are coprime.
+pub fn mod_inverse(a: i128, m: i128) -> Option<i128> {
+    if m == 1 {
+        return Some(0);
+    }
+    let (mut old_r, mut r) = (a.rem_euclid(m), m);
+    let (mut old_s, mut s) = (1, 0);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, old_s - q * s);
+    }
+    (old_r == 1).then(|| old_s.rem_euclid(m))
+}
+
+/// The integer square root, rounded down.
+pub fn isqrt(n: u128) -> u128 {
+    // The float estimate is off by at most a few units for large numbers, so correct it.
+    let mut root = (n as f64).sqrt() as u128;
+    while root * root > n {
+        root -= 1;
+    }
+    while (root + 1) * (root + 1) <= n {
+        root += 1;
+    }
+    root
+}
+
+/// The integers `x` for which `a·x² + b·x + c < 0`, with `a > 0`.
+/// Returns [`None`] if there are none, e.g. for `x² - 7x + 9 < 0` this is `2..=5`.
+pub fn quadratic_below_zero(a: i64, b: i64, c: i64) -> Option<RangeInclusive<i64>> {
+    assert!(a > 0, "the parabola must open upwards");
+
+    let (a, b, c) = (a as i128, b as i128, c as i128);
+    let discriminant = b * b - 4 * a * c;
+    if discriminant <= 0 {
+        return None;
+    }
+
+    // Start from the rounded roots and move inwards or outwards until exactly the integers below zero remain.
+    let f = |x: i128| a * x * x + b * x + c;
+    let root = isqrt(discriminant as u128) as i128;
+    let mut low = (-b - root).div_euclid(2 * a);
+    let mut high = (-b + root).div_euclid(2 * a) + 1;
+
+    while f(low) >= 0 && low <= high {
+        low += 1;
+    }
+    while f(low - 1) < 0 {
+        low -= 1;
+    }
+    while f(high) >= 0 && high >= low {
+        high -= 1;
+    }
+    while f(high + 1) < 0 {
+        high += 1;
+    }
+
+    (low <= high).then_some(low as i64..=high as i64)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{combine_congruences, crt, isqrt, lcm, lcm_all, quadratic_below_zero};
+
+    #[test]
+    fn computes_lcm() {
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm_all([2, 3, 4]), Some(12));
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+    }
+
+    #[test]
+    fn combines_congruences() {
+        assert_eq!(combine_congruences((2, 3), (3, 5)), Some((8, 15)));
+        assert_eq!(combine_congruences((1, 4), (3, 6)), Some((9, 12)));
+        assert_eq!(combine_congruences((1, 4), (2, 6)), None);
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+    }
+
+    #[test]
+    fn rejects_zero_moduli() {
+        assert_eq!(combine_congruences((2, 0), (3, 5)), None);
+        assert_eq!(combine_congruences((2, 3), (0, 0)), None);
+        assert_eq!(crt([(2, 3), (1, 0)]), None);
+    }
+
+    #[test]
+    fn computes_integer_square_roots() {
+        assert_eq!(isqrt(0), 0);
+        assert_eq!(isqrt(15), 3);
+        assert_eq!(isqrt(16), 4);
+        assert_eq!(isqrt(u64::MAX as u128), u32::MAX as u128);
+    }
+
+    #[test]
+    fn finds_integers_below_zero() {
+        // A race of 7ms with a record of 9mm: hold * (7 - hold) > 9
+        assert_eq!(quadratic_below_zero(1, -7, 9), Some(2..=5));
+        // Exact roots are excluded: hold * (30 - hold) > 200 has roots 10 and 20
+        assert_eq!(quadratic_below_zero(1, -30, 200), Some(11..=19));
+        assert_eq!(quadratic_below_zero(1, 0, 1), None);
+        assert_eq!(quadratic_below_zero(1, -2, 1), None);
+    }
+}
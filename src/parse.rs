@@ -0,0 +1,309 @@
+//! Helpers for the common shapes of puzzle inputs.
+//! They work on [`Span`]s, pieces of the input that know where they are, so that errors point at the offending line and column:
+//!
+//! ```
+//! use advent_of_code::parse::{lines, ParseError};
+//!
+//! fn parse(input: &str) -> Result<Vec<(u32, Vec<u32>)>, ParseError> {
+//!     // Card 1: 41 48 83 86 17
+//!     lines(input)
+//!         .map(|line| {
+//!             let (key, values) = line.key_value()?;
+//!             Ok((key.strip_prefix("Card")?.number()?, values.numbers()?))
+//!         })
+//!         .collect()
+//! }
+//!
+//! assert_eq!(parse("Card 1: 41 48").unwrap(), vec![(1, vec![41, 48])]);
+//! assert_eq!(
+//!     parse("Card 1: 41 x8").unwrap_err().to_string(),
+//!     "line 1, column 12: invalid number `x8`: invalid digit found in string"
+//! );
+//! ```
+use std::{fmt::Display, ops::Deref, str::FromStr};
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The line of the error, starting at 1.
+    pub line: usize,
+    /// The column of the error in characters, starting at 1.
+    pub column: usize,
+    pub message: String,
+}
+
+impl std::error::Error for ParseError {}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+// Solutions usually `unwrap()` their parse result, which prints the debug representation.
+impl std::fmt::Debug for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+/// A piece of the input together with its position.
+/// It dereferences to `&str`, so all string methods can be used on it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span<'a> {
+    text: &'a str,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Span<'a> {
+    /// A span of the whole input, starting at line 1, column 1.
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// An error located at the start of the span.
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError {
+            line: self.line,
+            column: self.column,
+            message: message.into(),
+        }
+    }
+
+    /// The part of the span starting at byte `start` and ending before byte `end`.
+    fn slice(&self, start: usize, end: usize) -> Self {
+        Self {
+            text: &self.text[start..end],
+            line: self.line,
+            column: self.column + self.text[..start].chars().count(),
+        }
+    }
+
+    pub fn trim(&self) -> Self {
+        let start = self.text.len() - self.text.trim_start().len();
+        let end = self.text.trim_end().len().max(start);
+        self.slice(start, end)
+    }
+
+    /// Removes `prefix` from the start of the span, or returns an error if the span does not start with it.
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Self, ParseError> {
+        if self.text.starts_with(prefix) {
+            Ok(self.slice(prefix.len(), self.text.len()))
+        } else {
+            Err(self.error(format!("expected `{prefix}`, found `{}`", self.text)))
+        }
+    }
+
+    /// Removes `suffix` from the end of the span, or returns an error if the span does not end with it.
+    pub fn strip_suffix(&self, suffix: &str) -> Result<Self, ParseError> {
+        if self.text.ends_with(suffix) {
+            Ok(self.slice(0, self.text.len() - suffix.len()))
+        } else {
+            Err(self.error(format!("expected `{}` to end with `{suffix}`", self.text)))
+        }
+    }
+
+    /// Splits the span at the first `separator`, or returns an error if it does not contain it.
+    pub fn split_once(&self, separator: &str) -> Result<(Self, Self), ParseError> {
+        match self.text.find(separator) {
+            Some(i) => Ok((
+                self.slice(0, i),
+                self.slice(i + separator.len(), self.text.len()),
+            )),
+            None => Err(self.error(format!("expected `{separator}` in `{}`", self.text))),
+        }
+    }
+
+    /// The pieces of the span between `separator`s.
+    pub fn split(&self, separator: &'a str) -> impl Iterator<Item = Span<'a>> {
+        let span = *self;
+        self.text
+            .match_indices(separator)
+            .map(|(i, _)| i)
+            .chain([self.text.len()])
+            .scan(0, move |start, end| {
+                let piece = span.slice(*start, end);
+                *start = end + separator.len();
+                Some(piece)
+            })
+    }
+
+    /// The pieces of the span separated by whitespace.
+    pub fn words(&self) -> impl Iterator<Item = Span<'a>> {
+        let span = *self;
+        self.text.split_whitespace().map(move |word| {
+            let start = word.as_ptr() as usize - span.text.as_ptr() as usize;
+            span.slice(start, start + word.len())
+        })
+    }
+
+    /// Parses the whole span, ignoring surrounding whitespace.
+    pub fn number<T>(&self) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let trimmed = self.trim();
+        trimmed
+            .text
+            .parse()
+            .map_err(|e| trimmed.error(format!("invalid number `{}`: {e}", trimmed.text)))
+    }
+
+    /// Parses all whitespace-separated numbers of the span, e.g. `7  15   30`.
+    pub fn numbers<T>(&self) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.words().map(|word| word.number()).collect()
+    }
+
+    /// Splits a `key: values` line at the colon and trims both sides.
+    pub fn key_value(&self) -> Result<(Self, Self), ParseError> {
+        let (key, values) = self.split_once(":")?;
+        Ok((key.trim(), values.trim()))
+    }
+
+    /// Parses a `A = (B, C)` record.
+    pub fn record(&self) -> Result<Record<'a>, ParseError> {
+        let (key, pair) = self.split_once("=")?;
+        let pair = pair.trim().strip_prefix("(")?.strip_suffix(")")?;
+        let (left, right) = pair.split_once(",")?;
+
+        Ok(Record {
+            key: key.trim(),
+            left: left.trim(),
+            right: right.trim(),
+        })
+    }
+}
+
+impl Deref for Span<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.text
+    }
+}
+
+impl Display for Span<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.text)
+    }
+}
+
+/// A `A = (B, C)` record, e.g. a node of a graph with its left and right neighbour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Record<'a> {
+    pub key: Span<'a>,
+    pub left: Span<'a>,
+    pub right: Span<'a>,
+}
+
+/// The non-empty lines of the input.
+pub fn lines(input: &str) -> impl Iterator<Item = Span<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, text)| Span {
+            text,
+            line: i + 1,
+            column: 1,
+        })
+}
+
+/// The blank-line-separated sections of the input, each with its lines.
+pub fn sections(input: &str) -> Vec<Vec<Span<'_>>> {
+    let mut sections = vec![];
+    let mut section = vec![];
+    let mut previous = 0;
+
+    for line in lines(input) {
+        if line.line > previous + 1 && !section.is_empty() {
+            sections.push(std::mem::take(&mut section));
+        }
+        previous = line.line;
+        section.push(line);
+    }
+
+    if !section.is_empty() {
+        sections.push(section);
+    }
+
+    sections
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{lines, sections, Span};
+
+    #[test]
+    fn parses_numbers_with_positions() {
+        let line = Span::new("Time:      7  15   30");
+        let (key, values) = line.key_value().unwrap();
+
+        assert_eq!(key.as_str(), "Time");
+        assert_eq!(values.numbers::<u32>().unwrap(), vec![7, 15, 30]);
+
+        let error = Span::new("1 2 -3").numbers::<u32>().unwrap_err();
+        assert_eq!((error.line, error.column), (1, 5));
+    }
+
+    #[test]
+    fn splits_with_positions() {
+        let line = lines("\nGame 1: 3 blue, 4 red; 1 red").next().unwrap();
+        let (_, values) = line.key_value().unwrap();
+        let pulls: Vec<Span> = values.split("; ").collect();
+
+        assert_eq!(pulls.len(), 2);
+        assert_eq!(pulls[1].as_str(), "1 red");
+        assert_eq!((pulls[1].line(), pulls[1].column()), (2, 24));
+        assert!(line.split_once("|").is_err());
+    }
+
+    #[test]
+    fn parses_records() {
+        let record = Span::new("AAA = (BBB, CCC)").record().unwrap();
+        assert_eq!(
+            [record.key, record.left, record.right].map(|s| s.as_str()),
+            ["AAA", "BBB", "CCC"]
+        );
+
+        let error = Span::new("AAA = BBB, CCC)").record().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 7: expected `(`, found `BBB, CCC)`"
+        );
+    }
+
+    #[test]
+    fn splits_sections() {
+        let sections = sections("seeds: 1 2\n\na map:\n1 2 3\n4 5 6\n\n\nb map:\n7 8 9\n");
+
+        assert_eq!(sections.len(), 3);
+        assert_eq!(sections[1].len(), 3);
+        assert_eq!(sections[2][1].line(), 9);
+    }
+}
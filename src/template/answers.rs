@@ -0,0 +1,236 @@
+/// Module that keeps a local ledger of submitted answers and their verdicts.
+/// Every day has its own ledger in `data/<year>/answers/<day>.toml`.
+use std::{
+    fmt::Display,
+    fs, io,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::{aoc_client::SubmissionOutcome, get_data_path};
+use crate::PuzzleId;
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse answer ledger: {e}"),
+            Error::IO(e) => write!(f, "could not access answer ledger: {e}"),
+        }
+    }
+}
+
+/// A single submission and the verdict it received.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Guess {
+    pub answer: String,
+    pub verdict: SubmissionOutcome,
+    /// Seconds since the unix epoch.
+    pub submitted_at: u64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PartLedger {
+    pub correct: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub guesses: Vec<Guess>,
+}
+
+/// What the ledger knows about an answer before it is submitted.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    /// The answer matches the correct answer on record.
+    Correct,
+    /// The answer is known to be wrong, contains the reason.
+    Wrong(String),
+    /// Nothing is known about the answer yet.
+    Unknown,
+}
+
+impl PartLedger {
+    /// The highest answer known to be too low.
+    pub fn lower_bound(&self) -> Option<i128> {
+        self.numeric_guesses(SubmissionOutcome::TooLow).max()
+    }
+
+    /// The lowest answer known to be too high.
+    pub fn upper_bound(&self) -> Option<i128> {
+        self.numeric_guesses(SubmissionOutcome::TooHigh).min()
+    }
+
+    fn numeric_guesses(&self, verdict: SubmissionOutcome) -> impl Iterator<Item = i128> + '_ {
+        self.guesses
+            .iter()
+            .filter(move |g| g.verdict == verdict)
+            .filter_map(|g| g.answer.parse().ok())
+    }
+
+    pub fn check(&self, answer: &str) -> Verdict {
+        if let Some(correct) = &self.correct {
+            if correct == answer {
+                return Verdict::Correct;
+            }
+            return Verdict::Wrong(format!("the correct answer on record is {correct}"));
+        }
+
+        let known_wrong = self.guesses.iter().find(|g| {
+            g.answer == answer
+                && matches!(
+                    g.verdict,
+                    SubmissionOutcome::Incorrect
+                        | SubmissionOutcome::TooHigh
+                        | SubmissionOutcome::TooLow
+                )
+        });
+
+        if let Some(guess) = known_wrong {
+            return Verdict::Wrong(format!("it was already submitted as {}", guess.verdict));
+        }
+
+        if let Ok(value) = answer.parse::<i128>() {
+            if let Some(bound) = self.lower_bound().filter(|bound| value <= *bound) {
+                return Verdict::Wrong(format!("{bound} is known to be too low"));
+            }
+            if let Some(bound) = self.upper_bound().filter(|bound| value >= *bound) {
+                return Verdict::Wrong(format!("{bound} is known to be too high"));
+            }
+        }
+
+        Verdict::Unknown
+    }
+
+    pub fn record(&mut self, answer: &str, verdict: SubmissionOutcome) {
+        if verdict == SubmissionOutcome::Correct {
+            self.correct = Some(answer.to_string());
+        }
+
+        self.guesses.push(Guess {
+            answer: answer.to_string(),
+            verdict,
+            submitted_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default(),
+        });
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Ledger {
+    #[serde(default)]
+    pub part_1: PartLedger,
+    #[serde(default)]
+    pub part_2: PartLedger,
+}
+
+impl Ledger {
+    /// Loads the ledger of a puzzle, returns an empty ledger if none was written yet.
+    pub fn load(puzzle: PuzzleId) -> Result<Self, Error> {
+        match fs::read_to_string(get_ledger_path(puzzle)) {
+            Ok(s) => Self::parse(&s),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn save(&self, puzzle: PuzzleId) -> Result<(), Error> {
+        let path = get_ledger_path(puzzle);
+        if let Some(dir) = Path::new(&path).parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let s = toml::to_string(self).map_err(|e| Error::Parser(e.to_string()))?;
+        fs::write(path, s)?;
+        Ok(())
+    }
+
+    fn parse(s: &str) -> Result<Self, Error> {
+        toml::from_str(s).map_err(|e| Error::Parser(e.to_string()))
+    }
+
+    pub fn part(&self, part: u8) -> &PartLedger {
+        if part == 1 {
+            &self.part_1
+        } else {
+            &self.part_2
+        }
+    }
+
+    pub fn part_mut(&mut self, part: u8) -> &mut PartLedger {
+        if part == 1 {
+            &mut self.part_1
+        } else {
+            &mut self.part_2
+        }
+    }
+}
+
+#[must_use]
+pub fn get_ledger_path(puzzle: PuzzleId) -> String {
+    get_data_path("answers", puzzle, "toml")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Ledger, PartLedger, Verdict};
+    use crate::template::aoc_client::SubmissionOutcome;
+
+    fn get_mock_ledger() -> PartLedger {
+        let mut ledger = PartLedger::default();
+        ledger.record("54194", SubmissionOutcome::TooLow);
+        ledger.record("54226", SubmissionOutcome::TooHigh);
+        ledger.record("54200", SubmissionOutcome::Incorrect);
+        ledger
+    }
+
+    #[test]
+    fn rejects_known_wrong_answers() {
+        let ledger = get_mock_ledger();
+        assert!(matches!(ledger.check("54200"), Verdict::Wrong(_)));
+        assert!(matches!(ledger.check("54194"), Verdict::Wrong(_)));
+    }
+
+    #[test]
+    fn rejects_answers_outside_bounds() {
+        let ledger = get_mock_ledger();
+        assert_eq!(ledger.lower_bound(), Some(54194));
+        assert_eq!(ledger.upper_bound(), Some(54226));
+        assert!(matches!(ledger.check("100"), Verdict::Wrong(_)));
+        assert!(matches!(ledger.check("60000"), Verdict::Wrong(_)));
+        assert_eq!(ledger.check("54208"), Verdict::Unknown);
+        assert_eq!(ledger.check("not a number"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn knows_correct_answer() {
+        let mut ledger = get_mock_ledger();
+        ledger.record("54208", SubmissionOutcome::Correct);
+        assert_eq!(ledger.check("54208"), Verdict::Correct);
+        assert!(matches!(ledger.check("54210"), Verdict::Wrong(_)));
+    }
+
+    #[test]
+    fn roundtrips_toml() {
+        let mut ledger = Ledger::default();
+        *ledger.part_mut(1) = get_mock_ledger();
+        ledger
+            .part_mut(2)
+            .record("42", SubmissionOutcome::TooRecent);
+
+        let s = toml::to_string(&ledger).unwrap();
+        assert!(s.contains("verdict = \"too_low\""));
+        assert_eq!(Ledger::parse(&s).unwrap(), ledger);
+    }
+}
@@ -0,0 +1,327 @@
+/// Minimal http client for adventofcode.com, replacing the "aoc-cli" wrapper.
+use std::{env, fmt::Display, fs, path::Path, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::{get_data_path, markdown::html_to_markdown};
+use crate::PuzzleId;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+static USER_AGENT: &str = concat!(
+    "advent_of_code/",
+    env!("CARGO_PKG_VERSION"),
+    " (github.com/fspoettel/advent-of-code-rust)"
+);
+
+#[derive(Debug)]
+pub enum AocClientError {
+    SessionNotFound,
+    BadStatus(u16),
+    Transport(String),
+    IoError,
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "no session cookie found. Set AOC_SESSION or create the file \"~/.adventofcode.session\"."
+            ),
+            AocClientError::BadStatus(status) => {
+                write!(f, "adventofcode.com responded with status {status}.")
+            }
+            AocClientError::Transport(e) => write!(f, "could not reach adventofcode.com: {e}"),
+            AocClientError::IoError => write!(f, "could not write output files to file system."),
+        }
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(status, _) => AocClientError::BadStatus(status),
+            ureq::Error::Transport(e) => AocClientError::Transport(e.to_string()),
+        }
+    }
+}
+
+/// The verdict adventofcode.com returned for a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SubmissionOutcome {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    /// The answer was submitted before the rate limit from a previous wrong answer ran out.
+    TooRecent,
+    /// The part was already solved or is not unlocked yet.
+    WrongLevel,
+    Unknown,
+}
+
+impl Display for SubmissionOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            SubmissionOutcome::Correct => "correct",
+            SubmissionOutcome::Incorrect => "incorrect",
+            SubmissionOutcome::TooHigh => "too high",
+            SubmissionOutcome::TooLow => "too low",
+            SubmissionOutcome::TooRecent => "too recent",
+            SubmissionOutcome::WrongLevel => "wrong level",
+            SubmissionOutcome::Unknown => "unknown",
+        };
+        f.write_str(s)
+    }
+}
+
+#[derive(Debug)]
+pub struct Submission {
+    pub outcome: SubmissionOutcome,
+    pub message: String,
+}
+
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    /// Creates a client that talks to the server at `base_url` and authenticates with `session`.
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    /// Creates a client from the environment:
+    ///  1. the base url is read from `AOC_BASE_URL` and defaults to [`DEFAULT_BASE_URL`].
+    ///  2. the session cookie is read from `AOC_SESSION` or the file `~/.adventofcode.session`.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        Ok(Self::new(&base_url, &read_session()?))
+    }
+
+    fn get_day_url(&self, puzzle: PuzzleId) -> String {
+        format!(
+            "{}/{}/day/{}",
+            self.base_url,
+            puzzle.year,
+            puzzle.day.into_inner()
+        )
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocClientError> {
+        self.agent
+            .get(url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()?
+            .into_string()
+            .map_err(|e| AocClientError::Transport(e.to_string()))
+    }
+
+    /// Fetches the personal puzzle input.
+    pub fn get_input(&self, puzzle: PuzzleId) -> Result<String, AocClientError> {
+        self.get(&format!("{}/input", self.get_day_url(puzzle)))
+    }
+
+    /// Fetches the puzzle description and converts it to markdown.
+    pub fn get_puzzle(&self, puzzle: PuzzleId) -> Result<String, AocClientError> {
+        let html = self.get(&self.get_day_url(puzzle))?;
+        Ok(html_to_markdown(&html))
+    }
+
+    /// Submits an answer for one part of a puzzle.
+    pub fn submit(
+        &self,
+        puzzle: PuzzleId,
+        part: u8,
+        answer: &str,
+    ) -> Result<Submission, AocClientError> {
+        let html = self
+            .agent
+            .post(&format!("{}/answer", self.get_day_url(puzzle)))
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?
+            .into_string()
+            .map_err(|e| AocClientError::Transport(e.to_string()))?;
+
+        let message = html_to_markdown(&html).trim().to_string();
+        Ok(Submission {
+            outcome: parse_outcome(&message),
+            message,
+        })
+    }
+}
+
+fn parse_outcome(message: &str) -> SubmissionOutcome {
+    let message = message.to_lowercase();
+
+    if message.contains("that's the right answer") {
+        SubmissionOutcome::Correct
+    } else if message.contains("your answer is too high") {
+        SubmissionOutcome::TooHigh
+    } else if message.contains("your answer is too low") {
+        SubmissionOutcome::TooLow
+    } else if message.contains("that's not the right answer") {
+        SubmissionOutcome::Incorrect
+    } else if message.contains("you gave an answer too recently") {
+        SubmissionOutcome::TooRecent
+    } else if message.contains("you don't seem to be solving the right level") {
+        SubmissionOutcome::WrongLevel
+    } else {
+        SubmissionOutcome::Unknown
+    }
+}
+
+fn get_session_path() -> Option<PathBuf> {
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+    Some(Path::new(&home).join(".adventofcode.session"))
+}
+
+/// Reads the session cookie from `AOC_SESSION`, falling back to the file `~/.adventofcode.session`.
+pub fn read_session() -> Result<String, AocClientError> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        if !session.trim().is_empty() {
+            return Ok(session.trim().to_string());
+        }
+    }
+
+    let path = get_session_path().ok_or(AocClientError::SessionNotFound)?;
+    match fs::read_to_string(path) {
+        Ok(session) if !session.trim().is_empty() => Ok(session.trim().to_string()),
+        _ => Err(AocClientError::SessionNotFound),
+    }
+}
+
+fn write_file(path: &str, contents: &str) -> Result<(), AocClientError> {
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir).map_err(|_| AocClientError::IoError)?;
+    }
+    fs::write(path, contents).map_err(|_| AocClientError::IoError)
+}
+
+/// Fetches the puzzle description, saves it and prints it to the terminal.
+pub fn read(puzzle: PuzzleId) -> Result<(), AocClientError> {
+    let client = AocClient::from_env()?;
+    let description = client.get_puzzle(puzzle)?;
+    write_file(&get_data_path("puzzles", puzzle, "md"), &description)?;
+    println!("{description}");
+    Ok(())
+}
+
+/// Fetches the input and the puzzle description and saves them to the data folder.
+pub fn download(puzzle: PuzzleId) -> Result<(), AocClientError> {
+    let input_path = get_data_path("inputs", puzzle, "txt");
+    let puzzle_path = get_data_path("puzzles", puzzle, "md");
+
+    let client = AocClient::from_env()?;
+    write_file(&input_path, &client.get_input(puzzle)?)?;
+    write_file(&puzzle_path, &client.get_puzzle(puzzle)?)?;
+
+    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    Ok(())
+}
+
+pub fn submit(puzzle: PuzzleId, part: u8, result: &str) -> Result<Submission, AocClientError> {
+    AocClient::from_env()?.submit(puzzle, part, result)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{AocClient, SubmissionOutcome};
+    use crate::{day, year, PuzzleId};
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    /// Serves a single request with `body` and returns the raw request that was received.
+    fn serve_once(body: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request = String::new();
+            let mut content_length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = len.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+
+            let mut payload = vec![0; content_length];
+            reader.read_exact(&mut payload).unwrap();
+            request.push_str(&String::from_utf8(payload).unwrap());
+
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    fn get_puzzle() -> PuzzleId {
+        PuzzleId::new(year!(2023), day!(5))
+    }
+
+    #[test]
+    fn fetches_input_with_session() {
+        let (base_url, server) = serve_once("1 2 3\n");
+        let client = AocClient::new(&base_url, "abc\n");
+
+        assert_eq!(client.get_input(get_puzzle()).unwrap(), "1 2 3\n");
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2023/day/5/input "));
+        assert!(request.contains("session=abc"));
+    }
+
+    #[test]
+    fn fetches_puzzle_as_markdown() {
+        let (base_url, _) = serve_once("<main><article><h2>--- Day 5 ---</h2></article></main>");
+        let client = AocClient::new(&base_url, "abc");
+
+        assert_eq!(
+            client.get_puzzle(get_puzzle()).unwrap(),
+            "## --- Day 5 ---\n"
+        );
+    }
+
+    #[test]
+    fn submits_answer() {
+        let (base_url, server) = serve_once(
+            "<article><p>That's not the right answer; your answer is too low.</p></article>",
+        );
+        let client = AocClient::new(&base_url, "abc");
+
+        let submission = client.submit(get_puzzle(), 2, "42").unwrap();
+        assert_eq!(submission.outcome, SubmissionOutcome::TooLow);
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2023/day/5/answer "));
+        assert!(request.ends_with("level=2&answer=42"));
+    }
+}
@@ -0,0 +1,168 @@
+/// Benchmarks solution parts and summarizes the measured samples.
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+/// The time spent benching a part if no budget is configured.
+pub const DEFAULT_BUDGET: Duration = Duration::from_secs(1);
+
+/// A part is sampled at least this often, even if that exceeds the budget.
+const MIN_SAMPLES: usize = 10;
+
+/// Caps the memory used for samples of very fast parts.
+const MAX_SAMPLES: usize = 100_000;
+
+const MAX_WARMUP_ITERATIONS: usize = 1_000;
+
+/// Summary of the samples taken while benching a part, outliers excluded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BenchStats {
+    pub samples: u64,
+    /// Samples outside of 1.5 times the inter-quartile range, these are excluded from all other values.
+    pub outliers: u64,
+    pub min_nanos: u64,
+    pub p5_nanos: u64,
+    pub median_nanos: u64,
+    pub p95_nanos: u64,
+    pub mean_nanos: u64,
+    pub stddev_nanos: u64,
+}
+
+impl BenchStats {
+    /// Computes the summary of a set of samples, returns [`None`] if there are none.
+    pub fn from_samples(samples: &[u64]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        // Tukey's fences: everything outside of 1.5 times the inter-quartile range is an outlier.
+        let q1 = percentile(&sorted, 0.25)? as f64;
+        let q3 = percentile(&sorted, 0.75)? as f64;
+        let iqr = q3 - q1;
+        let (lower, upper) = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+
+        let kept: Vec<u64> = sorted
+            .iter()
+            .copied()
+            .filter(|&x| (x as f64) >= lower && (x as f64) <= upper)
+            .collect();
+
+        let n = kept.len() as f64;
+        let mean = kept.iter().map(|&x| x as f64).sum::<f64>() / n;
+        let variance = kept.iter().map(|&x| (x as f64 - mean).powi(2)).sum::<f64>() / n;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Some(Self {
+            samples: sorted.len() as u64,
+            outliers: (sorted.len() - kept.len()) as u64,
+            min_nanos: *kept.first()?,
+            p5_nanos: percentile(&kept, 0.05)?,
+            median_nanos: percentile(&kept, 0.5)?,
+            p95_nanos: percentile(&kept, 0.95)?,
+            mean_nanos: mean.round() as u64,
+            stddev_nanos: variance.sqrt().round() as u64,
+        })
+    }
+
+    pub fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_nanos)
+    }
+
+    pub fn stddev(&self) -> Duration {
+        Duration::from_nanos(self.stddev_nanos)
+    }
+
+    /// Formats the median and the standard deviation, e.g. `1.2ms ± 30.5µs`.
+    pub fn spread(&self) -> String {
+        format!("{:.1?} ± {:.1?}", self.median(), self.stddev())
+    }
+}
+
+/// Nearest-rank percentile of sorted values.
+fn percentile(sorted: &[u64], p: f64) -> Option<u64> {
+    if sorted.is_empty() {
+        return None;
+    }
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let index = (p * (sorted.len() - 1) as f64).round() as usize;
+    sorted.get(index).copied()
+}
+
+/// Benches `func` for about `budget`:
+///  1. warm up for a tenth of the budget, unless a single run already exceeds that.
+///  2. sample until the budget is spent, but at least 10 times.
+pub fn run<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: Duration,
+    budget: Duration,
+) -> BenchStats {
+    let warmup = Instant::now();
+    let mut warmup_iterations = 0;
+
+    while warmup_iterations < MAX_WARMUP_ITERATIONS && warmup.elapsed() + base_time <= budget / 10 {
+        black_box(func(input.clone()));
+        warmup_iterations += 1;
+    }
+
+    let mut samples: Vec<u64> = vec![];
+    let start = Instant::now();
+
+    while samples.len() < MIN_SAMPLES || (samples.len() < MAX_SAMPLES && start.elapsed() < budget) {
+        // need a clone here to make the borrow checker happy.
+        let cloned = input.clone();
+        let timer = Instant::now();
+        black_box(func(cloned));
+        #[allow(clippy::cast_possible_truncation)]
+        samples.push(timer.elapsed().as_nanos() as u64);
+    }
+
+    // NOTE: at least `MIN_SAMPLES` samples were taken.
+    BenchStats::from_samples(&samples).unwrap()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{run, BenchStats};
+    use std::time::Duration;
+
+    #[test]
+    fn summarizes_samples() {
+        let samples: Vec<u64> = (1..=100).collect();
+        let stats = BenchStats::from_samples(&samples).unwrap();
+
+        assert_eq!(stats.samples, 100);
+        assert_eq!(stats.outliers, 0);
+        assert_eq!(stats.min_nanos, 1);
+        assert_eq!(stats.p5_nanos, 6);
+        assert_eq!(stats.median_nanos, 51);
+        assert_eq!(stats.p95_nanos, 95);
+        assert_eq!(stats.mean_nanos, 51);
+        assert_eq!(stats.stddev_nanos, 29);
+        assert_eq!(stats.spread(), "51.0ns ± 29.0ns");
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let mut samples: Vec<u64> = (90..=110).collect();
+        samples.extend([10_000, 1]);
+        let stats = BenchStats::from_samples(&samples).unwrap();
+
+        assert_eq!(stats.samples, 23);
+        assert_eq!(stats.outliers, 2);
+        assert_eq!(stats.min_nanos, 90);
+        assert_eq!(stats.median_nanos, 100);
+        assert_eq!(stats.p95_nanos, 109);
+    }
+
+    #[test]
+    fn no_samples() {
+        assert_eq!(BenchStats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn samples_at_least_ten_times() {
+        let stats = run(|x: u64| x * 2, 21, Duration::ZERO, Duration::ZERO);
+        assert_eq!(stats.samples, 10);
+    }
+}
@@ -0,0 +1,231 @@
+/// Module that keeps a local history of benchmark runs, so slowdowns between runs can be detected.
+/// Every `cargo time` run is appended to `data/<year>/bench_history.jsonl`, one run per line.
+use std::{
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::runner::PartRecord;
+use crate::Year;
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse benchmark history: {e}"),
+            Error::IO(e) => write!(f, "could not access benchmark history: {e}"),
+        }
+    }
+}
+
+/// The records of one benchmark run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BenchRun {
+    /// The commit that was checked out, if the repository is a git repository.
+    pub commit: Option<String>,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub records: Vec<PartRecord>,
+}
+
+impl BenchRun {
+    /// Creates a run of the current commit at the current time.
+    pub fn new(records: Vec<PartRecord>) -> Self {
+        Self {
+            commit: current_commit(),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default(),
+            records,
+        }
+    }
+
+    /// A short label for the run, e.g. `1a2b3c4 @ 1701388800`.
+    pub fn label(&self) -> String {
+        format!(
+            "{} @ {}",
+            self.commit.as_deref().unwrap_or("unknown commit"),
+            self.timestamp
+        )
+    }
+}
+
+fn current_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let commit = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (!commit.is_empty()).then_some(commit)
+}
+
+#[must_use]
+pub fn get_history_path(year: Year) -> String {
+    format!("data/{year}/bench_history.jsonl")
+}
+
+pub fn append(year: Year, run: &BenchRun) -> Result<(), Error> {
+    let path = get_history_path(year);
+    if let Some(dir) = Path::new(&path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let line = serde_json::to_string(run).map_err(|e| Error::Parser(e.to_string()))?;
+    let mut file = OpenOptions::new().append(true).create(true).open(path)?;
+    writeln!(file, "{line}")?;
+    Ok(())
+}
+
+/// Loads all runs of a year, oldest first. Returns no runs if the history was not written yet.
+pub fn load(year: Year) -> Result<Vec<BenchRun>, Error> {
+    match fs::read_to_string(get_history_path(year)) {
+        Ok(s) => parse(&s),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(e.into()),
+    }
+}
+
+fn parse(s: &str) -> Result<Vec<BenchRun>, Error> {
+    s.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| serde_json::from_str(line).map_err(|e| Error::Parser(e.to_string())))
+        .collect()
+}
+
+/// The timing of one part in a baseline run and in the latest run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartDiff {
+    pub day: u8,
+    pub part: u8,
+    pub baseline: Duration,
+    pub latest: Duration,
+    /// The relative change, e.g. `0.25` if the part got 25% slower.
+    pub change: f64,
+    /// The part got slower by more than the threshold.
+    pub is_regression: bool,
+}
+
+/// Compares the parts that were timed in both runs. A part regressed if it got slower than `threshold`, e.g. `0.1` for 10%.
+/// Parts that failed or timed out in either run are skipped, their durations do not measure the solution.
+pub fn diff(baseline: &BenchRun, latest: &BenchRun, threshold: f64) -> Vec<PartDiff> {
+    let is_timed = |record: &&PartRecord| record.error.is_none() && !record.timed_out;
+
+    latest
+        .records
+        .iter()
+        .filter(is_timed)
+        .filter_map(|record| {
+            let base = baseline
+                .records
+                .iter()
+                .filter(is_timed)
+                .find(|r| r.day == record.day && r.part == record.part)?;
+
+            if base.duration_nanos == 0 {
+                return None;
+            }
+
+            let change = record.duration_nanos as f64 / base.duration_nanos as f64 - 1.0;
+
+            Some(PartDiff {
+                day: record.day,
+                part: record.part,
+                baseline: base.duration(),
+                latest: record.duration(),
+                change,
+                is_regression: change > threshold,
+            })
+        })
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{diff, parse, BenchRun};
+    use crate::template::runner::PartRecord;
+
+    fn get_mock_run(commit: &str, durations: &[(u8, u8, u64)]) -> BenchRun {
+        BenchRun {
+            commit: Some(commit.into()),
+            timestamp: 1_701_388_800,
+            records: durations
+                .iter()
+                .map(|&(day, part, duration_nanos)| PartRecord {
+                    year: 2023,
+                    day,
+                    part,
+                    answer: Some("42".into()),
+                    error: None,
+                    duration_nanos,
+                    samples: 1,
+                    bench: None,
+                    parse_nanos: None,
+                    timed_out: false,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn flags_slowdowns_beyond_threshold() {
+        let baseline = get_mock_run("aaaaaaa", &[(1, 1, 1_000), (1, 2, 1_000), (2, 1, 500)]);
+        let latest = get_mock_run("bbbbbbb", &[(1, 1, 1_050), (1, 2, 2_000), (3, 1, 100)]);
+
+        let diffs = diff(&baseline, &latest, 0.1);
+
+        assert_eq!(diffs.len(), 2);
+        assert!(!diffs[0].is_regression);
+        assert!(diffs[1].is_regression);
+        assert_eq!(diffs[1].change, 1.0);
+    }
+
+    #[test]
+    fn skips_failed_parts() {
+        let mut baseline = get_mock_run("aaaaaaa", &[(1, 1, 1_000), (1, 2, 1_000), (2, 1, 1_000)]);
+        let mut latest = get_mock_run("bbbbbbb", &[(1, 1, 5_000), (1, 2, 5_000), (2, 1, 5_000)]);
+        baseline.records[1].timed_out = true;
+        latest.records[2].error = Some("panicked: explicit panic".into());
+
+        let diffs = diff(&baseline, &latest, 0.1);
+
+        assert_eq!(diffs.len(), 1);
+        assert_eq!((diffs[0].day, diffs[0].part), (1, 1));
+    }
+
+    #[test]
+    fn roundtrips_history() {
+        let runs = vec![
+            get_mock_run("aaaaaaa", &[(1, 1, 1_000)]),
+            get_mock_run("bbbbbbb", &[(1, 1, 2_000)]),
+        ];
+
+        let s = runs
+            .iter()
+            .map(|run| serde_json::to_string(run).unwrap() + "\n")
+            .collect::<String>();
+
+        assert_eq!(parse(&s).unwrap(), runs);
+    }
+}
@@ -0,0 +1,149 @@
+/// Runs a day with `solve` in a child process of the main binary, which contains every solution.
+/// A child can be killed when a part takes too long, and its output is buffered so several days can run at once.
+use std::env;
+use std::io::{BufRead, BufReader, Read};
+use std::process::{Child, Command, Stdio};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::template::runner::{print_record, OutputFormat, PartRecord, RunOptions};
+use crate::PuzzleId;
+
+/// The buffered output of a day that ran in a child process.
+#[derive(Debug, Clone)]
+pub struct ChildRun {
+    pub records: [PartRecord; 2],
+    /// Lines the solution printed to stdout itself.
+    pub output: Vec<String>,
+    pub stderr: String,
+}
+
+impl ChildRun {
+    /// Prints the output of the child in the output format of the run, the records like the runner would.
+    pub fn print(&self, options: &RunOptions) {
+        match options.format {
+            OutputFormat::Text => {
+                for line in &self.output {
+                    println!("{line}");
+                }
+                for record in &self.records {
+                    print_record(record);
+                }
+            }
+            OutputFormat::Json => {
+                for record in &self.records {
+                    // serializing a struct of plain values can not fail.
+                    println!("{}", serde_json::to_string(record).unwrap());
+                }
+            }
+        }
+
+        eprint!("{}", self.stderr);
+    }
+}
+
+/// Runs a day in a child process. A part that runs longer than `timeout` is killed and the parts after it are not run.
+/// The child reports every part as a JSON record as soon as it finishes.
+pub fn run(puzzle: PuzzleId, options: &RunOptions, timeout: Option<Duration>) -> ChildRun {
+    let mut child = match spawn_solve(puzzle, options) {
+        Ok(child) => child,
+        Err(e) => {
+            let error = format!("could not start the solution: {e}");
+            return ChildRun {
+                records: [1, 2].map(|part| PartRecord::failed(puzzle, part, error.clone())),
+                output: vec![],
+                stderr: String::new(),
+            };
+        }
+    };
+
+    let stdout = child.stdout.take().expect("stdout of the child is piped");
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            if sender.send(line).is_err() {
+                break;
+            }
+        }
+    });
+
+    let mut stderr = child.stderr.take().expect("stderr of the child is piped");
+    let stderr_reader = thread::spawn(move || {
+        let mut buffer = String::new();
+        let _ = stderr.read_to_string(&mut buffer);
+        buffer
+    });
+
+    let mut output = vec![];
+    // once a part did not finish, the parts after it never run.
+    let mut stopped: Option<String> = None;
+
+    let records = [1, 2].map(|part| {
+        if let Some(reason) = &stopped {
+            return PartRecord::failed(puzzle, part, reason.clone());
+        }
+
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+        loop {
+            let received = match deadline {
+                Some(deadline) => {
+                    receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+                }
+                None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+            };
+
+            match received {
+                Ok(line) => match serde_json::from_str::<PartRecord>(&line) {
+                    Ok(record) => break record,
+                    // solutions may print output of their own.
+                    Err(_) => output.push(line),
+                },
+                Err(RecvTimeoutError::Timeout) => {
+                    let _ = child.kill();
+                    stopped = Some(format!("not run, part {part} timed out"));
+                    break PartRecord::timed_out(puzzle, part, timeout.unwrap_or_default());
+                }
+                Err(RecvTimeoutError::Disconnected) => {
+                    let status = child
+                        .wait()
+                        .map_or_else(|e| e.to_string(), |status| status.to_string());
+                    let error = format!("the solution exited during part {part}: {status}");
+                    stopped = Some(error.clone());
+                    break PartRecord::failed(puzzle, part, error);
+                }
+            }
+        }
+    });
+
+    let _ = child.wait();
+
+    ChildRun {
+        records,
+        output,
+        stderr: stderr_reader.join().unwrap_or_default(),
+    }
+}
+
+fn spawn_solve(puzzle: PuzzleId, options: &RunOptions) -> std::io::Result<Child> {
+    let mut command = Command::new(env::current_exe()?);
+    command
+        .args([
+            "solve",
+            &puzzle.day.to_string(),
+            "--year",
+            &puzzle.year.to_string(),
+        ])
+        .args(["--format", "json"])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    if options.time {
+        command.arg("--time");
+    }
+    if let Some(bench_time) = options.bench_time {
+        command.args(["--bench-time", &format!("{}ns", bench_time.as_nanos())]);
+    }
+
+    command.spawn()
+}
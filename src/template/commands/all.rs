@@ -1,254 +1,364 @@
-use std::io;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::time::Duration;
+use std::{fs, process, thread};
 
 use crate::template::{
+    answers::Ledger,
+    bench_history::{self, BenchRun},
+    child::{self, ChildRun},
+    get_data_path,
     readme_benchmarks::{self, Timings},
+    registry::{Registry, Solution},
+    runner::{OutputFormat, PartRecord, RunOptions},
     ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
 };
-use crate::{all_days, Day};
+use crate::{Day, DayRange, DaySet, PuzzleId, Year};
+
+/// Options that control which days `all` runs and how.
+#[derive(Debug, Clone)]
+pub struct AllOptions {
+    /// Compare the answers to the correct answers in the ledgers.
+    pub check: bool,
+    /// Run every day in a child process and kill parts that run longer than this.
+    pub timeout: Option<Duration>,
+    /// How many days run at the same time, each in a child process.
+    pub jobs: usize,
+    /// The days to run, all days by default.
+    pub days: DaySet,
+    /// Days that are not run, even if they are part of `days`.
+    pub skip: DaySet,
+    /// Only run days from this day on.
+    pub since: Option<Day>,
+    /// Only run days that have a solution, instead of listing the others as not solved.
+    pub only_solved: bool,
+}
 
-pub fn handle(is_release: bool, is_timed: bool) {
-    let mut timings: Vec<Timings> = vec![];
+impl AllOptions {
+    /// The days selected by `--days`, `--skip` and `--since`.
+    pub fn selected_days(&self) -> DaySet {
+        let since = self
+            .since
+            .map_or_else(DaySet::all, |day| DayRange::since(day).into());
+        self.days.difference(&self.skip).intersection(&since)
+    }
+}
 
-    all_days().for_each(|day| {
-        if day > 1 {
-            println!();
+impl Default for AllOptions {
+    fn default() -> Self {
+        Self {
+            check: false,
+            timeout: None,
+            jobs: 1,
+            days: DaySet::all(),
+            skip: DaySet::new(),
+            since: None,
+            only_solved: false,
         }
+    }
+}
 
-        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-        println!("------");
+/// What `all` does with a day.
+enum Plan<'a> {
+    /// The day has not been scaffolded yet.
+    NotSolved,
+    MissingInput(String),
+    Run(&'a Solution, String),
+}
 
-        let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+impl<'a> Plan<'a> {
+    fn new(registry: &'a Registry, puzzle: PuzzleId) -> Self {
+        let Some(solution) = registry.get(puzzle) else {
+            return Plan::NotSolved;
+        };
 
-        if output.is_empty() {
-            println!("Not solved.");
-        } else {
-            let val = child_commands::parse_exec_time(&output, day);
-            timings.push(val);
+        let input_path = get_data_path("inputs", puzzle, "txt");
+        match fs::read_to_string(&input_path) {
+            Ok(input) => Plan::Run(solution, input),
+            Err(_) => Plan::MissingInput(input_path),
         }
-    });
+    }
+}
 
-    if is_timed {
-        let total_millis = timings.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
+pub fn handle(registry: &Registry, year: Year, options: &RunOptions, all_options: &AllOptions) {
+    let is_check = all_options.check;
+    let mut records: Vec<PartRecord> = vec![];
+    let mut checks: Vec<(PuzzleId, [CheckStatus; 2])> = vec![];
+
+    // in json mode, stdout only contains the records printed by the runner.
+    let is_text = options.format == OutputFormat::Text;
+
+    let selected_days = all_options.selected_days();
+    let plans: Vec<(PuzzleId, Plan)> = selected_days
+        .iter()
+        .map(|day| PuzzleId::new(year, day))
+        .filter(|&puzzle| !all_options.only_solved || registry.get(puzzle).is_some())
+        .map(|puzzle| (puzzle, Plan::new(registry, puzzle)))
+        .collect();
+
+    if plans.is_empty() {
+        eprintln!("No days selected.");
+        return;
+    }
 
-        println!("\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}");
+    // benchmarks of days that run at the same time would slow each other down.
+    let jobs = if options.time && all_options.jobs > 1 {
+        eprintln!("Ignoring `--jobs` for `--time`, benchmarks run one day after another.");
+        1
+    } else {
+        all_options.jobs.max(1)
+    };
 
-        if is_release {
-            match readme_benchmarks::update(timings, total_millis) {
-                Ok(()) => println!("Successfully updated README with benchmarks."),
-                Err(_) => {
-                    eprintln!("Failed to update readme with benchmarks.");
+    run_days(
+        &plans,
+        options,
+        all_options.timeout,
+        jobs,
+        |puzzle, plan, run| {
+            if is_text {
+                if Some(&puzzle) != plans.first().map(|(first, _)| first) {
+                    println!();
                 }
+
+                println!("{ANSI_BOLD}Day {}{ANSI_RESET}", puzzle.day);
+                println!("------");
             }
-        }
-    }
-}
 
-#[derive(Debug)]
-pub enum Error {
-    BrokenPipe,
-    Parser(String),
-    IO(io::Error),
-}
+            let [part_1, part_2] = match plan {
+                Plan::NotSolved => {
+                    if is_text {
+                        println!("Not solved.");
+                    }
+                    return;
+                }
+                Plan::MissingInput(input_path) => {
+                    eprintln!("Could not read input file \"{input_path}\".");
+                    // a day that can not run no longer produces its correct answers.
+                    if is_check {
+                        checks.push((puzzle, check_answers(puzzle, [None, None])));
+                    }
+                    return;
+                }
+                Plan::Run(..) => run(),
+            };
 
-impl From<std::io::Error> for Error {
-    fn from(e: std::io::Error) -> Self {
-        Error::IO(e)
-    }
-}
+            if is_check {
+                let answers = [part_1.answer.clone(), part_2.answer.clone()];
+                checks.push((puzzle, check_answers(puzzle, answers)));
+            }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
+            records.push(part_1);
+            records.push(part_2);
+        },
+    );
 
-/// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
-mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::Day;
-    use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
-        thread,
-    };
+    // failed parts are reported apart from parts that are not solved yet.
+    let errors: Vec<PartRecord> = records
+        .iter()
+        .filter(|r| r.error.is_some() || r.timed_out)
+        .cloned()
+        .collect();
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
-        }
+    if is_text && !errors.is_empty() {
+        print_errors(&errors);
+    }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+    if options.time {
+        let timings = Timings::from_records(&records);
+        let total_millis = timings.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
 
-        if is_release {
-            args.push("--release");
+        if is_text {
+            println!(
+                "\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+            );
         }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+        // the benchmarks of a subset of days would replace those of all days in the readme.
+        if selected_days != DaySet::all() {
+            if is_text {
+                println!("Not recording benchmarks of a subset of days.");
+            }
+        // timings of unoptimized builds are not representative.
+        } else if !cfg!(debug_assertions) {
+            match bench_history::append(year, &BenchRun::new(records)) {
+                Ok(()) => {
+                    if is_text {
+                        println!("Recorded benchmarks in the benchmark history.");
+                    }
+                }
+                Err(e) => eprintln!("{e}"),
+            }
+
+            match readme_benchmarks::update(timings, total_millis) {
+                Ok(()) => {
+                    if is_text {
+                        println!("Successfully updated README with benchmarks.");
+                    }
+                }
+                Err(_) => {
+                    eprintln!("Failed to update readme with benchmarks.");
+                }
+            }
         }
+    }
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+    if is_check {
+        if is_text {
+            print_check_matrix(&checks);
+        }
 
-        let mut cmd = Command::new("cargo")
-            .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
+        if checks
+            .iter()
+            .any(|(_, parts)| parts.iter().any(|p| matches!(p, CheckStatus::Fail { .. })))
+        {
+            process::exit(1);
+        }
+    }
+}
 
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+fn print_errors(errors: &[PartRecord]) {
+    println!("\n{ANSI_BOLD}Errors{ANSI_RESET}");
+    println!("------");
 
-        let mut output = vec![];
+    for record in errors {
+        let (day, part) = (record.day, record.part);
+        if record.timed_out {
+            println!(
+                "Day {day} Part {part}: TIMEOUT after {:.1?}",
+                record.duration()
+            );
+        } else if let Some(error) = &record.error {
+            println!("Day {day} Part {part}: {error}");
+        }
+    }
+}
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
+/// Runs the days in order and hands each one to `report`, together with a function that returns its records.
+/// With a single job, solutions run inside this process unless there is a timeout.
+/// With several jobs, days run in child processes at the same time, and are reported in order once they are done.
+fn run_days(
+    plans: &[(PuzzleId, Plan)],
+    options: &RunOptions,
+    timeout: Option<Duration>,
+    jobs: usize,
+    mut report: impl FnMut(PuzzleId, &Plan, &mut dyn FnMut() -> [PartRecord; 2]),
+) {
+    if jobs == 1 {
+        for (puzzle, plan) in plans {
+            report(*puzzle, plan, &mut || match (plan, timeout) {
+                (Plan::Run(solution, input), None) => solution.run(input, options),
+                _ => print_child_run(child::run(*puzzle, options, timeout), options),
             });
-        });
-
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
         }
-
-        thread.join().unwrap();
-        cmd.wait()?;
-
-        Ok(output)
+        return;
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timings {
-        let mut timings = super::Timings {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
-
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
+    let queue: Vec<(usize, PuzzleId)> = plans
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, plan))| matches!(plan, Plan::Run(..)))
+        .map(|(index, (puzzle, _))| (index, *puzzle))
+        .collect();
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel::<(usize, ChildRun)>();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(queue.len()) {
+            let sender = sender.clone();
+            let (queue, next) = (&queue, &next);
+            scope.spawn(move || {
+                while let Some(&(index, puzzle)) = queue.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    if sender
+                        .send((index, child::run(puzzle, options, timeout)))
+                        .is_err()
+                    {
+                        break;
+                    }
                 }
-
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
-
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+            });
+        }
+        drop(sender);
+
+        // days that finished before the days in front of them wait here until it is their turn.
+        let mut finished: HashMap<usize, ChildRun> = HashMap::new();
+        for (index, (puzzle, plan)) in plans.iter().enumerate() {
+            report(*puzzle, plan, &mut || {
+                while !finished.contains_key(&index) {
+                    let (done, run) = receiver.recv().expect("every queued day is run");
+                    finished.insert(done, run);
                 }
-
-                timings.total_nanos += nanos;
+                print_child_run(finished.remove(&index).unwrap(), options)
             });
+        }
+    });
+}
 
-        timings
-    }
+fn print_child_run(run: ChildRun, options: &RunOptions) -> [PartRecord; 2] {
+    run.print(options);
+    run.records
+}
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
-    }
+/// The result of comparing the output of a part to the correct answer in its ledger.
+#[derive(Debug, PartialEq, Eq)]
+pub enum CheckStatus {
+    Pass,
+    Fail {
+        expected: String,
+        actual: Option<String>,
+    },
+    /// There is no correct answer on record to compare against.
+    Unchecked,
+}
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
-    }
+fn check_answers(puzzle: PuzzleId, answers: [Option<String>; 2]) -> [CheckStatus; 2] {
+    let ledger = match Ledger::load(puzzle) {
+        Ok(ledger) => ledger,
+        Err(e) => {
+            eprintln!("{e}");
+            return [CheckStatus::Unchecked, CheckStatus::Unchecked];
+        }
+    };
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+    let [part_1, part_2] = answers;
+    [
+        check_answer(ledger.part_1.correct, part_1),
+        check_answer(ledger.part_2.correct, part_2),
+    ]
+}
+
+fn check_answer(expected: Option<String>, actual: Option<String>) -> CheckStatus {
+    match expected {
+        None => CheckStatus::Unchecked,
+        Some(expected) if actual.as_ref() == Some(&expected) => CheckStatus::Pass,
+        Some(expected) => CheckStatus::Fail { expected, actual },
     }
+}
 
-    #[cfg(feature = "test_lib")]
-    mod tests {
-        use super::parse_exec_time;
-
-        use crate::day;
-
-        #[test]
-        fn test_well_formed() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
-        }
+fn print_check_matrix(checks: &[(PuzzleId, [CheckStatus; 2])]) {
+    println!("\n{ANSI_BOLD}Check{ANSI_RESET}");
+    println!("------");
+    println!("Day  Part 1  Part 2");
 
-        #[test]
-        fn test_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
-        }
+    for (puzzle, parts) in checks {
+        let [part_1, part_2] = parts.each_ref().map(|status| match status {
+            CheckStatus::Pass => "✔",
+            CheckStatus::Fail { .. } => "✘",
+            CheckStatus::Unchecked => "-",
+        });
+        println!("{}   {part_1}       {part_2}", puzzle.day);
+    }
 
-        #[test]
-        fn test_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
+    for (puzzle, parts) in checks {
+        for (i, status) in parts.iter().enumerate() {
+            if let CheckStatus::Fail { expected, actual } = status {
+                println!(
+                    "Day {} Part {}: expected {expected}, got {}",
+                    puzzle.day,
+                    i + 1,
+                    actual.as_deref().unwrap_or("✖")
+                );
+            }
         }
     }
 }
@@ -0,0 +1,75 @@
+use std::process;
+
+use crate::template::{
+    bench_history::{self, BenchRun},
+    ANSI_BOLD, ANSI_RESET,
+};
+use crate::Year;
+
+/// Compares the latest run in the benchmark history with `baseline`, a commit hash (prefix), or the run before it.
+pub fn handle(year: Year, baseline: Option<String>, threshold: f64) {
+    let runs = match bench_history::load(year) {
+        Ok(runs) => runs,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    let Some((latest, earlier)) = runs.split_last() else {
+        eprintln!("No benchmark runs recorded for {year}. Type `cargo time` to record one.");
+        process::exit(1);
+    };
+
+    let Some(base) = find_baseline(earlier, baseline.as_deref()) else {
+        match baseline {
+            Some(commit) => eprintln!("No benchmark run recorded for commit {commit}."),
+            None => eprintln!("Only one benchmark run recorded, nothing to compare with."),
+        }
+        process::exit(1);
+    };
+
+    println!(
+        "{ANSI_BOLD}Baseline:{ANSI_RESET} {}\n{ANSI_BOLD}Latest:{ANSI_RESET}   {}\n",
+        base.label(),
+        latest.label()
+    );
+
+    let diffs = bench_history::diff(base, latest, threshold);
+
+    for diff in &diffs {
+        let marker = if diff.is_regression { "✘" } else { "✔" };
+        println!(
+            "{marker} Day {:02} Part {}: {:.1?} → {:.1?} ({:+.1}%)",
+            diff.day,
+            diff.part,
+            diff.baseline,
+            diff.latest,
+            diff.change * 100.0
+        );
+    }
+
+    let regressions = diffs.iter().filter(|d| d.is_regression).count();
+
+    if regressions > 0 {
+        println!(
+            "\n{regressions} part(s) got more than {:.0}% slower.",
+            threshold * 100.0
+        );
+        process::exit(1);
+    }
+
+    println!("\nNo part got more than {:.0}% slower.", threshold * 100.0);
+}
+
+/// Picks the most recent earlier run of `commit`, or the run right before the latest one.
+fn find_baseline<'a>(earlier: &'a [BenchRun], commit: Option<&str>) -> Option<&'a BenchRun> {
+    match commit {
+        Some(commit) => earlier.iter().rev().find(|run| {
+            run.commit
+                .as_deref()
+                .is_some_and(|c| c.starts_with(commit) || commit.starts_with(c))
+        }),
+        None => earlier.last(),
+    }
+}
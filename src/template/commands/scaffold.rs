@@ -1,18 +1,30 @@
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    path::Path,
     process,
+    str::FromStr,
 };
 
-use crate::Day;
+use crate::template::{
+    examples::{self, Declaration, Manifest},
+    get_bin_path, get_data_path,
+};
+use crate::PuzzleId;
+
+/// A template in this location takes precedence over [`MODULE_TEMPLATE`].
+const TEMPLATE_PATH: &str = "templates/solution.rs";
 
-const MODULE_TEMPLATE: &str = r#"advent_of_code::solution!(DAY_NUMBER);
+/// The placeholders `YEAR_NUMBER`, `DAY_NUMBER`, `PUZZLE_TITLE`, `ANSWER_TYPE` and `EXAMPLE_TESTS` are replaced
+/// when a module is created. The placeholders of [`LITERAL_TESTS`] can be used in templates too.
+const MODULE_TEMPLATE: &str = r#"// PUZZLE_TITLE
+advent_of_code::solution!(YEAR_NUMBER, DAY_NUMBER);
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn part_one(input: &str) -> Option<ANSWER_TYPE> {
     None
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &str) -> Option<ANSWER_TYPE> {
     None
 }
 
@@ -20,75 +32,457 @@ pub fn part_two(input: &str) -> Option<u32> {
 mod tests {
     use super::*;
 
-    #[test]
+EXAMPLE_TESTS}
+"#;
+
+/// The tests of a module without an example manifest, `EXAMPLE_PART_ONE`, `EXAMPLE_PART_TWO`, `EXPECTED_PART_ONE`
+/// and `EXPECTED_PART_TWO` are replaced. Modules with a manifest test its examples with `test_examples!` instead.
+const LITERAL_TESTS: &str = r#"    #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        let result = part_one(&EXAMPLE_PART_ONE);
+        assert_eq!(result, EXPECTED_PART_ONE);
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        let result = part_two(&EXAMPLE_PART_TWO);
+        assert_eq!(result, EXPECTED_PART_TWO);
     }
-}
 "#;
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+const MANIFEST_TESTS: &str = "    advent_of_code::test_examples!();\n";
+
+/// The type of the answers returned by a scaffolded solution.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AnswerType {
+    #[default]
+    U32,
+    U64,
+    I64,
+    String,
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+impl AnswerType {
+    fn rust_type(self) -> &'static str {
+        match self {
+            Self::U32 => "u32",
+            Self::U64 => "u64",
+            Self::I64 => "i64",
+            Self::String => "String",
+        }
+    }
+
+    /// Returns a literal of this type for `answer`, if the answer fits the type.
+    fn literal(self, answer: &str) -> Option<String> {
+        let fits = match self {
+            Self::U32 => answer.parse::<u32>().is_ok(),
+            Self::U64 => answer.parse::<u64>().is_ok(),
+            Self::I64 => answer.parse::<i64>().is_ok(),
+            Self::String => return Some(format!("{answer:?}.to_string()")),
+        };
+        fits.then(|| answer.to_string())
+    }
 }
 
-pub fn handle(day: Day) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+impl FromStr for AnswerType {
+    type Err = AnswerTypeFromStrError;
 
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {e}");
-            process::exit(1);
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "u32" => Ok(Self::U32),
+            "u64" => Ok(Self::U64),
+            "i64" => Ok(Self::I64),
+            "String" | "string" => Ok(Self::String),
+            _ => Err(AnswerTypeFromStrError),
         }
+    }
+}
+
+/// An error which can be returned when parsing an [`AnswerType`].
+#[derive(Debug)]
+pub struct AnswerTypeFromStrError;
+
+impl std::error::Error for AnswerTypeFromStrError {}
+
+impl Display for AnswerTypeFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting an answer type of either `u32`, `u64`, `i64` or `String`")
+    }
+}
+
+/// Options that control how a day is scaffolded.
+#[derive(Debug, Clone, Default)]
+pub struct ScaffoldOptions {
+    /// Extract the examples from the downloaded puzzle description.
+    pub examples: bool,
+    pub answer_type: AnswerType,
+    /// Overwrite an existing module file.
+    pub force: bool,
+    /// Only report what would be written.
+    pub dry_run: bool,
+}
+
+fn create_parent_dir(path: &str) -> Result<(), std::io::Error> {
+    match Path::new(path).parent() {
+        Some(dir) => fs::create_dir_all(dir),
+        None => Ok(()),
+    }
+}
+
+/// Writes `contents` to `path`, or only reports what would be written in a dry run.
+fn write_file(path: &str, contents: &str, description: &str, options: &ScaffoldOptions) {
+    let verb = if Path::new(path).exists() {
+        "Overwrote"
+    } else {
+        "Created"
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("DAY_NUMBER", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
-        Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
-        }
+    if options.dry_run {
+        println!("Would write {description} \"{path}\"");
+        return;
+    }
+
+    if let Err(e) = create_parent_dir(path).and_then(|()| fs::write(path, contents)) {
+        eprintln!("Failed to write {description}: {e}");
+        process::exit(1);
+    }
+
+    println!("{verb} {description} \"{path}\"");
+}
+
+/// Creates an empty file unless it exists already.
+fn touch_file(path: &str, description: &str, options: &ScaffoldOptions) {
+    if Path::new(path).exists() {
+        return;
+    }
+
+    if options.dry_run {
+        println!("Would create empty {description} \"{path}\"");
+        return;
+    }
+
+    let result = create_parent_dir(path).and_then(|()| {
+        OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)
+    });
+
+    match result {
+        Ok(_) => println!("Created empty {description} \"{path}\""),
         Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
+            eprintln!("Failed to create {description}: {e}");
             process::exit(1);
         }
     }
+}
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+/// Reads the title from a puzzle description, e.g. `Trebuchet?!` from `## --- Day 1: Trebuchet?! ---`.
+fn parse_title(markdown: &str) -> Option<String> {
+    let heading = markdown.lines().find(|l| l.starts_with("## --- Day "))?;
+    let title = heading.split_once(": ")?.1.trim_end_matches("---").trim();
+    (!title.is_empty()).then(|| title.to_string())
+}
+
+/// How the tests of a scaffolded module read their examples.
+#[derive(Default)]
+struct ExampleFiles {
+    /// Both parts share an example, or each part has its own example file.
+    is_per_part: bool,
+    expected: [Option<String>; 2],
+    /// The expected answers are declared in the example manifest.
+    has_manifest: bool,
+}
+
+fn render_module(
+    template: &str,
+    puzzle: PuzzleId,
+    title: Option<&str>,
+    example_files: &ExampleFiles,
+    answer_type: AnswerType,
+) -> String {
+    let day = puzzle.day.into_inner();
+
+    let read_example = |part: u8| {
+        if example_files.is_per_part {
+            format!("advent_of_code::template::read_file_part(\"examples\", PUZZLE, {part})")
+        } else {
+            "advent_of_code::template::read_file(\"examples\", PUZZLE)".to_string()
         }
+    };
+
+    let expected = |part: usize| match example_files.expected[part]
+        .as_deref()
+        .and_then(|answer| answer_type.literal(answer))
+    {
+        Some(literal) => format!("Some({literal})"),
+        None => "None".to_string(),
+    };
+
+    let tests = if example_files.has_manifest {
+        MANIFEST_TESTS
+    } else {
+        LITERAL_TESTS
+    };
+
+    template
+        .replace("EXAMPLE_TESTS", tests)
+        .replace("YEAR_NUMBER", &puzzle.year.into_inner().to_string())
+        .replace("DAY_NUMBER", &day.to_string())
+        .replace(
+            "PUZZLE_TITLE",
+            &match title {
+                Some(title) => format!("Day {day}: {title}"),
+                None => format!("Day {day}"),
+            },
+        )
+        .replace("ANSWER_TYPE", answer_type.rust_type())
+        .replace("EXAMPLE_PART_ONE", &read_example(1))
+        .replace("EXAMPLE_PART_TWO", &read_example(2))
+        .replace("EXPECTED_PART_ONE", &expected(0))
+        .replace("EXPECTED_PART_TWO", &expected(1))
+}
+
+/// Writes the examples found in the downloaded puzzle description to the examples folder and declares their answers.
+fn write_examples(puzzle: PuzzleId, markdown: &str, options: &ScaffoldOptions) -> ExampleFiles {
+    let [part_one, part_two] = examples::extract(markdown);
+
+    let Some(part_one) = part_one else {
+        eprintln!(
+            "Could not find an example in \"{}\".",
+            get_data_path("puzzles", puzzle, "md")
+        );
+        process::exit(1);
+    };
+
+    let is_per_part = part_two
+        .as_ref()
+        .is_some_and(|part_two| part_two.input != part_one.input);
+
+    let expected = [
+        part_one.answer.clone(),
+        part_two.as_ref().and_then(|e| e.answer.clone()),
+    ];
+
+    // every example file is declared with the answers of the parts that use it.
+    let declarations = match &part_two {
+        Some(part_two) if is_per_part => vec![
+            (
+                format!("{}-1.txt", puzzle.day),
+                &part_one,
+                [&expected[0], &None],
+            ),
+            (
+                format!("{}-2.txt", puzzle.day),
+                part_two,
+                [&None, &expected[1]],
+            ),
+        ],
+        _ => vec![(
+            format!("{}.txt", puzzle.day),
+            &part_one,
+            [&expected[0], &expected[1]],
+        )],
+    };
+
+    // examples the user may have edited are only replaced with `--force`, new ones are added.
+    let manifest = if options.force {
+        Ok(Manifest::default())
+    } else {
+        Manifest::read(puzzle)
+    };
+    let mut manifest = match manifest {
+        Ok(manifest) => manifest,
         Err(e) => {
-            eprintln!("Failed to create input file: {e}");
+            eprintln!("{e}");
             process::exit(1);
         }
+    };
+    let mut is_manifest_changed = options.force;
+
+    for (file, example, [part_1, part_2]) in declarations {
+        let path = format!("data/{}/examples/{file}", puzzle.year);
+        if options.force || !Path::new(&path).exists() {
+            write_file(&path, &example.input, "example file", options);
+        } else {
+            println!(
+                "Example file \"{path}\" already exists, skipping. Pass `--force` to overwrite it."
+            );
+        }
+
+        is_manifest_changed |= manifest.merge(Declaration {
+            file,
+            part_1: part_1.clone(),
+            part_2: part_2.clone(),
+        });
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+    let manifest_path = examples::get_manifest_path(puzzle);
+
+    if !is_manifest_changed {
+        println!("Expected answers in \"{manifest_path}\" are up to date.");
+    } else if options.dry_run {
+        println!("Would declare expected answers in \"{manifest_path}\"");
+    } else {
+        match manifest.save(puzzle) {
+            Ok(()) => println!("Declared expected answers in \"{manifest_path}\""),
+            Err(e) => {
+                eprintln!("{e}");
+                process::exit(1);
+            }
         }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
+    }
+
+    ExampleFiles {
+        is_per_part,
+        expected,
+        has_manifest: true,
+    }
+}
+
+pub fn handle(puzzle: PuzzleId, options: &ScaffoldOptions) {
+    let input_path = get_data_path("inputs", puzzle, "txt");
+    let example_path = get_data_path("examples", puzzle, "txt");
+    let puzzle_path = get_data_path("puzzles", puzzle, "md");
+    let module_path = get_bin_path(puzzle);
+
+    let module_exists = Path::new(&module_path).exists();
+
+    if module_exists && !options.force && !options.examples {
+        eprintln!("Module file \"{module_path}\" already exists. Pass `--force` to overwrite it.");
+        process::exit(1);
+    }
+
+    let markdown = fs::read_to_string(&puzzle_path).ok();
+
+    let example_files = match (&markdown, options.examples) {
+        (Some(markdown), true) => write_examples(puzzle, markdown, options),
+        (None, true) => {
+            eprintln!(
+                "Could not read puzzle description \"{puzzle_path}\". Type `cargo download {} --year {}` first.",
+                puzzle.day, puzzle.year
+            );
             process::exit(1);
         }
+        (_, false) => ExampleFiles::default(),
+    };
+
+    // examples can be extracted again for an existing module, e.g. once part two is unlocked.
+    if module_exists && !options.force {
+        println!("Module file \"{module_path}\" already exists, skipping.");
+        if example_files.is_per_part {
+            println!("Both parts have their own example, read them in tests with `read_file_part(\"examples\", PUZZLE, <part>)`.");
+        }
+        return;
+    }
+
+    let template = match fs::read_to_string(TEMPLATE_PATH) {
+        Ok(template) => {
+            println!("Using template \"{TEMPLATE_PATH}\"");
+            template
+        }
+        Err(_) => MODULE_TEMPLATE.to_string(),
+    };
+
+    let module = render_module(
+        &template,
+        puzzle,
+        markdown.as_deref().and_then(parse_title).as_deref(),
+        &example_files,
+        options.answer_type,
+    );
+
+    write_file(&module_path, &module, "module file", options);
+
+    if options.dry_run {
+        println!("---");
+        print!("{module}");
+    }
+
+    touch_file(&input_path, "input file", options);
+
+    if !options.examples {
+        touch_file(&example_path, "example file", options);
+    }
+
+    if options.dry_run {
+        return;
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {}` to run your solution.", day);
+    println!(
+        "🎄 Type `cargo solve {} --year {}` to run your solution.",
+        puzzle.day, puzzle.year
+    );
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_title, render_module, AnswerType, ExampleFiles, MODULE_TEMPLATE};
+    use crate::{day, year, PuzzleId};
+
+    #[test]
+    fn parses_title() {
+        assert_eq!(
+            parse_title("## --- Day 1: Trebuchet?! ---\n\nSomething is wrong."),
+            Some("Trebuchet?!".into())
+        );
+        assert_eq!(parse_title("no heading"), None);
+    }
+
+    #[test]
+    fn renders_module() {
+        let example_files = ExampleFiles {
+            is_per_part: true,
+            expected: [Some("142".into()), Some("-3".into())],
+            has_manifest: false,
+        };
+
+        let module = render_module(
+            MODULE_TEMPLATE,
+            PuzzleId::new(year!(2023), day!(1)),
+            Some("Trebuchet?!"),
+            &example_files,
+            AnswerType::U64,
+        );
+
+        assert!(module.starts_with("// Day 1: Trebuchet?!\nadvent_of_code::solution!(2023, 1);"));
+        assert!(module.contains("pub fn part_one(input: &str) -> Option<u64> {"));
+        assert!(module.contains("read_file_part(\"examples\", PUZZLE, 2)"));
+        assert!(module.contains("assert_eq!(result, Some(142));"));
+        // a negative answer does not fit an unsigned type.
+        assert!(module.contains("assert_eq!(result, None);"));
+    }
+
+    #[test]
+    fn renders_example_tests_with_manifest() {
+        let example_files = ExampleFiles {
+            is_per_part: true,
+            expected: [Some("142".into()), Some("281".into())],
+            has_manifest: true,
+        };
+
+        let module = render_module(
+            MODULE_TEMPLATE,
+            PuzzleId::new(year!(2023), day!(1)),
+            None,
+            &example_files,
+            AnswerType::U32,
+        );
+
+        // the answers are only declared in the manifest.
+        assert!(module.contains("    advent_of_code::test_examples!();\n}"));
+        assert!(!module.contains("142"));
+    }
+
+    #[test]
+    fn renders_string_answers() {
+        assert_eq!(
+            AnswerType::String.literal("AB"),
+            Some("\"AB\".to_string()".into())
+        );
+        assert_eq!(AnswerType::I64.literal("-3"), Some("-3".into()));
+        assert_eq!(AnswerType::U32.literal("5000000000"), None);
+    }
 }
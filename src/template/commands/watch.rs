@@ -0,0 +1,202 @@
+/// Re-runs a day whenever its solution, input or examples change, started by `cargo solve <day> --watch`.
+/// Solutions are compiled into the running binary, so every run rebuilds the solution's own binary with cargo.
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+use std::io;
+use std::path::PathBuf;
+use std::process::{Command, Output};
+use std::time::{Duration, SystemTime};
+use std::{env, fs, thread};
+
+use crate::template::runner::{print_record, Outcome, PartRecord};
+use crate::template::{get_bin_path, get_data_path, ANSI_BOLD, ANSI_RESET};
+use crate::PuzzleId;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+/// Editors may save a file in several writes, changes within this time after the first one are handled together.
+const SETTLE_TIME: Duration = Duration::from_millis(100);
+
+pub fn handle(puzzle: PuzzleId) {
+    let mut snapshot = Snapshot::take(puzzle);
+    let mut changed: Vec<PathBuf> = vec![];
+    let mut previous: [Option<Outcome>; 2] = [None, None];
+
+    loop {
+        previous = run(puzzle, &changed, previous);
+
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            changed = Snapshot::take(puzzle).changes(&snapshot);
+            if !changed.is_empty() {
+                thread::sleep(SETTLE_TIME);
+                snapshot = Snapshot::take(puzzle);
+                break;
+            }
+        }
+    }
+}
+
+/// The modification times of the files of a day: its solution, its input and its examples.
+/// Examples belong to a day if their name starts with the day, e.g. `03.txt`, `03-2.txt` and `03.toml`.
+struct Snapshot(BTreeMap<PathBuf, Option<SystemTime>>);
+
+impl Snapshot {
+    fn take(puzzle: PuzzleId) -> Self {
+        let mut paths = vec![
+            PathBuf::from(get_bin_path(puzzle)),
+            PathBuf::from(get_data_path("inputs", puzzle, "txt")),
+        ];
+
+        let examples = PathBuf::from(get_data_path("examples", puzzle, "txt"));
+        let day = puzzle.day.to_string();
+        if let Some(Ok(entries)) = examples.parent().map(fs::read_dir) {
+            paths.extend(
+                entries
+                    .filter_map(Result::ok)
+                    .map(|e| e.path())
+                    .filter(|path| {
+                        path.file_name()
+                            .and_then(|name| name.to_str())
+                            .and_then(|name| name.strip_prefix(&day))
+                            .is_some_and(|rest| rest.starts_with(['.', '-']))
+                    }),
+            );
+        }
+
+        Self(
+            paths
+                .into_iter()
+                .map(|path| {
+                    let modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+                    (path, modified)
+                })
+                .collect(),
+        )
+    }
+
+    /// The files that were changed, created or removed since `earlier`.
+    fn changes(&self, earlier: &Self) -> Vec<PathBuf> {
+        let mut changed: Vec<PathBuf> = self
+            .0
+            .iter()
+            .filter(|(path, modified)| earlier.0.get(*path) != Some(modified))
+            .map(|(path, _)| path.clone())
+            .collect();
+
+        changed.extend(
+            earlier
+                .0
+                .keys()
+                .filter(|path| !self.0.contains_key(*path))
+                .cloned(),
+        );
+
+        changed
+    }
+}
+
+/// Builds the solution, runs its tests and then runs it against the puzzle input.
+/// Returns the outcome of both parts, or the previous outcomes if the solution could not be built.
+fn run(
+    puzzle: PuzzleId,
+    changed: &[PathBuf],
+    previous: [Option<Outcome>; 2],
+) -> [Option<Outcome>; 2] {
+    // clear the screen and move the cursor to its top left.
+    print!("\x1b[2J\x1b[H");
+    println!("{ANSI_BOLD}Watching {puzzle}{ANSI_RESET}, press Ctrl-C to stop.");
+    for path in changed {
+        println!("Changed {}", path.display());
+    }
+
+    let bin = puzzle.to_string();
+
+    println!("\n{ANSI_BOLD}Build{ANSI_RESET}");
+    match cargo(&["build", "--release", "--quiet", "--bin", &bin]) {
+        Ok(output) if output.status.success() => println!("✔ built"),
+        Ok(output) => {
+            print_output(&output);
+            return previous;
+        }
+        Err(e) => {
+            eprintln!("Could not run cargo: {e}");
+            return previous;
+        }
+    }
+
+    println!("\n{ANSI_BOLD}Examples{ANSI_RESET}");
+    match cargo(&["test", "--quiet", "--bin", &bin]) {
+        Ok(output) if output.status.success() => println!("✔ passed"),
+        Ok(output) => print_output(&output),
+        Err(e) => eprintln!("Could not run cargo: {e}"),
+    }
+
+    println!("\n{ANSI_BOLD}Input{ANSI_RESET}");
+    let output = match cargo(&[
+        "run",
+        "--release",
+        "--quiet",
+        "--bin",
+        &bin,
+        "--",
+        "--format",
+        "json",
+    ]) {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("Could not run cargo: {e}");
+            return previous;
+        }
+    };
+
+    let mut records: Vec<PartRecord> = vec![];
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        match serde_json::from_str(line) {
+            Ok(record) => records.push(record),
+            // solutions may print output of their own.
+            Err(_) => println!("{line}"),
+        }
+    }
+    eprint!("{}", String::from_utf8_lossy(&output.stderr));
+
+    for record in &records {
+        print_record(record);
+    }
+
+    let mut outcomes = previous.clone();
+    for record in &records {
+        let index = if record.part == 1 { 0 } else { 1 };
+        let outcome = record.outcome();
+        if let Some(before) = &previous[index] {
+            if *before != outcome {
+                println!(
+                    "Part {} changed: {} → {}",
+                    record.part,
+                    describe(before),
+                    describe(&outcome)
+                );
+            }
+        }
+        outcomes[index] = Some(outcome);
+    }
+
+    outcomes
+}
+
+fn describe(outcome: &Outcome) -> &str {
+    match outcome {
+        Outcome::Answer(answer) => answer,
+        Outcome::Unsolved => "✖",
+        Outcome::Error(_) => "error",
+    }
+}
+
+fn cargo(args: &[&str]) -> io::Result<Output> {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+    Command::new(cargo).args(args).output()
+}
+
+fn print_output(output: &Output) {
+    print!("{}", String::from_utf8_lossy(&output.stdout));
+    eprint!("{}", String::from_utf8_lossy(&output.stderr));
+}
@@ -0,0 +1,437 @@
+/// Module that reads the examples of a puzzle together with their expected answers.
+/// A puzzle can have several examples, e.g. `data/<year>/examples/01-1.txt` and `01-2.txt`.
+/// They are declared in `data/<year>/examples/<day>.toml`:
+///
+/// ```toml
+/// [[example]]
+/// file = "01-1.txt"
+/// part_1 = 142
+///
+/// [[example]]
+/// file = "01-2.txt"
+/// part_2 = 281
+/// ```
+use std::{fmt::Display, fs, io, path::Path};
+
+use regex::Regex;
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::template::get_data_path;
+use crate::PuzzleId;
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse examples: {e}"),
+            Error::IO(e) => write!(f, "could not read examples: {e}"),
+        }
+    }
+}
+
+/// An example input and the answers it is expected to produce.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    /// The file name of the example, relative to the examples folder.
+    pub file: String,
+    pub input: String,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+impl Example {
+    pub fn expected(&self, part: u8) -> Option<&str> {
+        if part == 1 {
+            self.part_1.as_deref()
+        } else {
+            self.part_2.as_deref()
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Manifest {
+    #[serde(default, rename = "example")]
+    pub examples: Vec<Declaration>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Declaration {
+    pub file: String,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_answer",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub part_1: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_answer",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub part_2: Option<String>,
+}
+
+impl Manifest {
+    /// Reads the manifest of a puzzle, returns an empty manifest if there is none.
+    pub fn read(puzzle: PuzzleId) -> Result<Self, Error> {
+        match fs::read_to_string(get_manifest_path(puzzle)) {
+            Ok(s) => parse(&s),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Adds a declaration without touching what is declared already:
+    /// a file that is declared gains only the answers it does not declare yet.
+    /// Returns whether the manifest changed.
+    pub fn merge(&mut self, declaration: Declaration) -> bool {
+        let Some(existing) = self
+            .examples
+            .iter_mut()
+            .find(|existing| existing.file == declaration.file)
+        else {
+            self.examples.push(declaration);
+            return true;
+        };
+
+        let mut is_changed = false;
+        for (answer, new) in [
+            (&mut existing.part_1, declaration.part_1),
+            (&mut existing.part_2, declaration.part_2),
+        ] {
+            if answer.is_none() && new.is_some() {
+                *answer = new;
+                is_changed = true;
+            }
+        }
+        is_changed
+    }
+
+    pub fn save(&self, puzzle: PuzzleId) -> Result<(), Error> {
+        let s = toml::to_string(self).map_err(|e| Error::Parser(e.to_string()))?;
+        fs::write(get_manifest_path(puzzle), s)?;
+        Ok(())
+    }
+}
+
+/// Answers can be declared as numbers or strings, they are compared as strings.
+fn deserialize_answer<'de, D: Deserializer<'de>>(d: D) -> Result<Option<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Answer {
+        Integer(i64),
+        String(String),
+    }
+
+    Ok(Some(match Answer::deserialize(d)? {
+        Answer::Integer(n) => n.to_string(),
+        Answer::String(s) => s,
+    }))
+}
+
+#[must_use]
+pub fn get_manifest_path(puzzle: PuzzleId) -> String {
+    get_data_path("examples", puzzle, "toml")
+}
+
+/// Loads all examples declared for a puzzle, returns no examples if none are declared.
+pub fn load(puzzle: PuzzleId) -> Result<Vec<Example>, Error> {
+    let path = get_manifest_path(puzzle);
+    let manifest = Manifest::read(puzzle)?;
+
+    let dir = Path::new(&path).parent().unwrap_or(Path::new("."));
+
+    manifest
+        .examples
+        .into_iter()
+        .map(|declaration| {
+            Ok(Example {
+                input: fs::read_to_string(dir.join(&declaration.file))?,
+                file: declaration.file,
+                part_1: declaration.part_1,
+                part_2: declaration.part_2,
+            })
+        })
+        .collect()
+}
+
+fn parse(s: &str) -> Result<Manifest, Error> {
+    toml::from_str(s).map_err(|e| {
+        let message = e.to_string();
+        if message.contains("number too large") {
+            Error::Parser(format!(
+                "{message}answers larger than {} have to be quoted, e.g. `part_1 = \"{}\"`.",
+                i64::MAX,
+                u64::MAX
+            ))
+        } else {
+            Error::Parser(message)
+        }
+    })
+}
+
+/// An example found in a puzzle description.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PuzzleExample {
+    pub input: String,
+    /// The last emphasized code in the description of the part, which usually is the answer to the example.
+    pub answer: Option<String>,
+}
+
+/// Finds the examples of both parts in a puzzle description converted to markdown.
+/// The example of a part is the first code block that follows a paragraph containing "For example".
+/// If the second part has no example of its own, it reuses the example of the first part.
+pub fn extract(markdown: &str) -> [Option<PuzzleExample>; 2] {
+    let (part_one, part_two) = match markdown.find("## --- Part Two") {
+        Some(index) => markdown.split_at(index),
+        None => (markdown, ""),
+    };
+
+    let example_one = extract_part(part_one, None);
+    let example_two = extract_part(
+        part_two,
+        example_one.as_ref().map(|example| example.input.as_str()),
+    );
+
+    [example_one, example_two]
+}
+
+fn extract_part(section: &str, fallback: Option<&str>) -> Option<PuzzleExample> {
+    let mut lines = section.lines();
+    let mut is_after_for_example = false;
+    let mut input: Option<String> = None;
+
+    while let Some(line) = lines.next() {
+        if line.starts_with("```") {
+            let block: Vec<&str> = lines
+                .by_ref()
+                .take_while(|l| !l.starts_with("```"))
+                .collect();
+            if is_after_for_example {
+                input = Some(block.join("\n") + "\n");
+                break;
+            }
+        } else if line.to_lowercase().contains("for example") {
+            is_after_for_example = true;
+        }
+    }
+
+    let answer = find_emphasized_code(section).last().cloned();
+
+    match (input, fallback) {
+        (Some(input), _) => Some(PuzzleExample { input, answer }),
+        (None, Some(fallback)) if answer.is_some() => Some(PuzzleExample {
+            input: fallback.to_string(),
+            answer,
+        }),
+        _ => None,
+    }
+}
+
+fn find_emphasized_code(s: &str) -> Vec<String> {
+    // NOTE: the pattern is valid.
+    let re = Regex::new(r"\*`([^`]+)`\*").unwrap();
+    re.captures_iter(s).map(|c| c[1].to_string()).collect()
+}
+
+/// Generates a test that runs every example declared for the puzzle against the parts it has an expected answer for.
+/// Pass the parse function of a two-phase solution, e.g. `test_examples!(parse)`, or `test_examples!(parse?)` if it is fallible.
+#[macro_export]
+macro_rules! test_examples {
+    () => {
+        $crate::test_examples!(@test input => input);
+    };
+    ($parse:ident) => {
+        $crate::test_examples!(@test input => $parse(input));
+    };
+    ($parse:ident ?) => {
+        $crate::test_examples!(@test input => $parse(input).unwrap_or_else(|e| panic!("{e}")));
+    };
+    (@test $input:ident => $parse:expr) => {
+        #[test]
+        fn test_examples() {
+            use advent_of_code::template::runner::{Outcome, PartResult};
+
+            let examples = advent_of_code::template::examples::load(PUZZLE)
+                .unwrap_or_else(|e| panic!("{e}"));
+
+            assert!(
+                !examples.is_empty(),
+                "no examples declared in {}",
+                advent_of_code::template::examples::get_manifest_path(PUZZLE)
+            );
+
+            for example in &examples {
+                let $input = example.input.as_str();
+                let parsed = $parse;
+
+                if let Some(expected) = example.expected(1) {
+                    let actual = part_one(&parsed).outcome();
+                    assert_eq!(actual, Outcome::Answer(expected.into()), "part 1 of {}", example.file);
+                }
+
+                if let Some(expected) = example.expected(2) {
+                    let actual = part_two(&parsed).outcome();
+                    assert_eq!(actual, Outcome::Answer(expected.into()), "part 2 of {}", example.file);
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{extract, parse, Declaration, Error, PuzzleExample};
+
+    fn get_mock_description(part_two_example: &str) -> String {
+        [
+            "## --- Day 1: Trebuchet?! ---",
+            "",
+            "Some text with *`emphasis`* before the example.",
+            "",
+            "```",
+            "not an example",
+            "```",
+            "",
+            "For example:",
+            "",
+            "```",
+            "1abc2",
+            "treb7uchet",
+            "```",
+            "",
+            "Adding these together produces *`142`*.",
+            "",
+            "## --- Part Two ---",
+            "",
+            part_two_example,
+            "",
+            "Adding these together produces *`281`*.",
+        ]
+        .join("\n")
+    }
+
+    #[test]
+    fn extracts_examples() {
+        let markdown = get_mock_description("For example:\n\n```\ntwo1nine\n```");
+        let [part_one, part_two] = extract(&markdown);
+
+        assert_eq!(
+            part_one,
+            Some(PuzzleExample {
+                input: "1abc2\ntreb7uchet\n".into(),
+                answer: Some("142".into())
+            })
+        );
+        assert_eq!(
+            part_two,
+            Some(PuzzleExample {
+                input: "two1nine\n".into(),
+                answer: Some("281".into())
+            })
+        );
+    }
+
+    #[test]
+    fn reuses_example_of_part_one() {
+        let markdown = get_mock_description("Using the same example:");
+        let [part_one, part_two] = extract(&markdown);
+
+        assert_eq!(part_two.unwrap().input, part_one.unwrap().input);
+    }
+
+    #[test]
+    fn extracts_nothing_without_example() {
+        assert_eq!(extract("## --- Day 1 ---\n\nNo examples."), [None, None]);
+    }
+
+    #[test]
+    fn parses_manifest() {
+        let manifest = parse(
+            r#"
+[[example]]
+file = "01-1.txt"
+part_1 = 142
+
+[[example]]
+file = "01-2.txt"
+part_2 = "281"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(manifest.examples.len(), 2);
+        assert_eq!(manifest.examples[0].file, "01-1.txt");
+        assert_eq!(manifest.examples[0].part_1.as_deref(), Some("142"));
+        assert_eq!(manifest.examples[0].part_2, None);
+        assert_eq!(manifest.examples[1].part_2.as_deref(), Some("281"));
+    }
+
+    #[test]
+    fn merges_declarations() {
+        let mut manifest = parse(
+            r#"
+[[example]]
+file = "01.txt"
+part_1 = "edited"
+"#,
+        )
+        .unwrap();
+
+        let declaration = |file: &str, part_1: &str, part_2: Option<&str>| Declaration {
+            file: file.into(),
+            part_1: Some(part_1.into()),
+            part_2: part_2.map(str::to_string),
+        };
+
+        assert!(!manifest.merge(declaration("01.txt", "142", None)));
+        assert!(manifest.merge(declaration("01.txt", "142", Some("281"))));
+        assert!(manifest.merge(declaration("01-2.txt", "1", None)));
+
+        assert_eq!(manifest.examples[0].part_1.as_deref(), Some("edited"));
+        assert_eq!(manifest.examples[0].part_2.as_deref(), Some("281"));
+        assert_eq!(manifest.examples[1].file, "01-2.txt");
+    }
+
+    #[test]
+    fn parses_large_answers() {
+        let manifest = parse(
+            r#"
+[[example]]
+file = "01.txt"
+part_1 = "18446744073709551615"
+part_2 = 9223372036854775807
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            manifest.examples[0].part_1.as_deref(),
+            Some("18446744073709551615")
+        );
+        assert_eq!(
+            manifest.examples[0].part_2.as_deref(),
+            Some("9223372036854775807")
+        );
+
+        let error = parse("[[example]]\nfile = \"01.txt\"\npart_1 = 18446744073709551615\n");
+        assert!(matches!(error, Err(Error::Parser(e)) if e.contains("have to be quoted")));
+    }
+
+    #[test]
+    fn parses_empty_manifest() {
+        assert!(parse("").unwrap().examples.is_empty());
+    }
+}
@@ -0,0 +1,130 @@
+/// Selects the input a solution runs against.
+use std::{
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::template::get_data_path;
+use crate::PuzzleId;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// The puzzle input in `data/<year>/inputs`.
+    #[default]
+    Puzzle,
+    /// The example in `data/<year>/examples`, e.g. `01.txt`, or the example of one part, e.g. `01-2.txt`.
+    Example(Option<u8>),
+    /// Any file, e.g. somebody else's puzzle input.
+    File(PathBuf),
+    /// Whatever is piped into the process.
+    Stdin,
+}
+
+impl InputSource {
+    /// Reads the `--example [part]`, `--input <path>` and `--stdin` options, at most one of them may be passed.
+    pub fn parse(args: &mut pico_args::Arguments) -> Result<Self, String> {
+        // the arguments are only removed if the value is a part, otherwise `--example` is a flag.
+        let example = match args.opt_value_from_fn("--example", parse_part) {
+            Ok(Some(part)) => Some(Some(part)),
+            _ => args.contains("--example").then_some(None),
+        };
+        let stdin = args.contains("--stdin");
+        let file: Option<PathBuf> = args
+            .opt_value_from_os_str("--input", |s| Ok::<_, String>(PathBuf::from(s)))
+            .map_err(|e| e.to_string())?;
+
+        match (example, stdin, file) {
+            (None, false, None) => Ok(Self::Puzzle),
+            (Some(part), false, None) => Ok(Self::Example(part)),
+            (None, true, None) => Ok(Self::Stdin),
+            (None, false, Some(path)) => Ok(Self::File(path)),
+            _ => Err("only one of `--example`, `--input` and `--stdin` can be passed.".into()),
+        }
+    }
+
+    pub fn read(&self, puzzle: PuzzleId) -> io::Result<String> {
+        match self {
+            Self::Puzzle => fs::read_to_string(get_data_path("inputs", puzzle, "txt")),
+            Self::Example(part) => {
+                fs::read_to_string(example_path(puzzle, *part)).map_err(|e| {
+                    // days with a different example per part have no shared example file.
+                    let has_part_examples = [1, 2]
+                        .iter()
+                        .any(|&part| Path::new(&example_path(puzzle, Some(part))).exists());
+                    if part.is_none() && e.kind() == io::ErrorKind::NotFound && has_part_examples {
+                        io::Error::new(
+                            e.kind(),
+                            format!("{e}, pass `--example 1` or `--example 2` to run the example of a part"),
+                        )
+                    } else {
+                        e
+                    }
+                })
+            }
+            Self::File(path) => fs::read_to_string(path),
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+
+    /// A description of the input for messages, e.g. `"data/2023/examples/01.txt"`.
+    pub fn describe(&self, puzzle: PuzzleId) -> String {
+        match self {
+            Self::Puzzle => format!("\"{}\"", get_data_path("inputs", puzzle, "txt")),
+            Self::Example(part) => format!("\"{}\"", example_path(puzzle, *part)),
+            Self::File(path) => format!("\"{}\"", path.display()),
+            Self::Stdin => "stdin".into(),
+        }
+    }
+}
+
+fn parse_part(s: &str) -> Result<u8, String> {
+    match s {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("{s} is not a part")),
+    }
+}
+
+fn example_path(puzzle: PuzzleId, part: Option<u8>) -> String {
+    match part {
+        Some(part) => format!("data/{}/examples/{}-{part}.txt", puzzle.year, puzzle.day),
+        None => get_data_path("examples", puzzle, "txt"),
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::InputSource;
+    use std::ffi::OsString;
+    use std::path::PathBuf;
+
+    fn parse(args: &[&str]) -> Result<InputSource, String> {
+        let mut args = pico_args::Arguments::from_vec(args.iter().map(OsString::from).collect());
+        InputSource::parse(&mut args)
+    }
+
+    #[test]
+    fn parses_input_sources() {
+        assert_eq!(parse(&[]), Ok(InputSource::Puzzle));
+        assert_eq!(parse(&["--example"]), Ok(InputSource::Example(None)));
+        assert_eq!(
+            parse(&["--example", "2"]),
+            Ok(InputSource::Example(Some(2)))
+        );
+        assert_eq!(
+            parse(&["--example", "--stdin"]),
+            Err("only one of `--example`, `--input` and `--stdin` can be passed.".into())
+        );
+        assert_eq!(parse(&["--stdin"]), Ok(InputSource::Stdin));
+        assert_eq!(
+            parse(&["--input", "other.txt"]),
+            Ok(InputSource::File(PathBuf::from("other.txt")))
+        );
+        assert!(parse(&["--example", "--stdin"]).is_err());
+    }
+}
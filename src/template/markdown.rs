@@ -0,0 +1,218 @@
+//! Converts the html served by adventofcode.com to markdown.
+//! Only the small subset of html used in puzzle descriptions is supported.
+
+/// Converts the `<article>` elements of an html page to markdown, discarding everything else.
+#[must_use]
+pub fn html_to_markdown(html: &str) -> String {
+    let articles: Vec<String> = html
+        .split("<article")
+        .skip(1)
+        .filter_map(|s| {
+            let body = &s[s.find('>')? + 1..];
+            let end = body.find("</article>").unwrap_or(body.len());
+            Some(convert(&body[..end]))
+        })
+        .collect();
+
+    let mut markdown = articles.join("\n\n");
+    markdown.push('\n');
+    markdown
+}
+
+/// Keeps track of the element we are in while walking the html.
+#[derive(Default)]
+struct State {
+    out: String,
+    in_pre: bool,
+    links: Vec<String>,
+    // `Some` while inside an inline `<code>` element, stores its text and whether it was emphasized.
+    code: Option<(String, bool)>,
+}
+
+impl State {
+    fn push_text(&mut self, text: &str) {
+        if let Some((code, _)) = self.code.as_mut() {
+            code.push_str(text);
+        } else if self.in_pre {
+            self.out.push_str(text);
+        } else {
+            let text = text.replace('\n', " ");
+            // whitespace between block elements carries no meaning.
+            if text.trim().is_empty() && (self.out.is_empty() || self.out.ends_with('\n')) {
+                return;
+            }
+            self.out.push_str(&text);
+        }
+    }
+
+    fn break_block(&mut self) {
+        if self.out.is_empty() {
+            return;
+        }
+        while !self.out.ends_with("\n\n") {
+            self.out.push('\n');
+        }
+    }
+
+    fn open(&mut self, name: &str, tag: &str) {
+        match name {
+            "h2" => {
+                self.break_block();
+                self.out.push_str("## ");
+            }
+            "p" | "ul" => self.break_block(),
+            "li" => self.out.push_str("- "),
+            "br" => self.out.push('\n'),
+            "pre" => {
+                self.break_block();
+                self.out.push_str("```\n");
+                self.in_pre = true;
+            }
+            "code" if !self.in_pre => self.code = Some((String::new(), false)),
+            "em" if !self.in_pre => match self.code.as_mut() {
+                Some((_, emphasized)) => *emphasized = true,
+                None => self.out.push('*'),
+            },
+            "a" => {
+                self.links
+                    .push(get_attribute(tag, "href").unwrap_or_default());
+                self.out.push('[');
+            }
+            _ => {}
+        }
+    }
+
+    fn close(&mut self, name: &str) {
+        match name {
+            "h2" | "p" | "ul" => self.break_block(),
+            "li" => self.out.push('\n'),
+            "pre" => {
+                if !self.out.ends_with('\n') {
+                    self.out.push('\n');
+                }
+                self.out.push_str("```");
+                self.in_pre = false;
+                self.break_block();
+            }
+            "code" if !self.in_pre => {
+                if let Some((code, emphasized)) = self.code.take() {
+                    if emphasized {
+                        self.out.push_str(&format!("*`{code}`*"));
+                    } else {
+                        self.out.push_str(&format!("`{code}`"));
+                    }
+                }
+            }
+            "em" if !self.in_pre && self.code.is_none() => self.out.push('*'),
+            "a" => {
+                let href = self.links.pop().unwrap_or_default();
+                self.out.push_str(&format!("]({href})"));
+            }
+            _ => {}
+        }
+    }
+}
+
+fn convert(html: &str) -> String {
+    let mut state = State::default();
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        state.push_text(&decode_entities(&rest[..start]));
+
+        let Some(end) = rest[start..].find('>') else {
+            break;
+        };
+
+        let tag = &rest[start + 1..start + end];
+        rest = &rest[start + end + 1..];
+
+        let (is_closing, tag) = match tag.strip_prefix('/') {
+            Some(tag) => (true, tag),
+            None => (false, tag),
+        };
+
+        let name = tag
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        if is_closing {
+            state.close(&name);
+        } else {
+            state.open(&name, tag);
+        }
+    }
+
+    state.push_text(&decode_entities(rest));
+    state.out.trim().to_string()
+}
+
+fn get_attribute(tag: &str, attribute: &str) -> Option<String> {
+    let needle = format!("{attribute}=\"");
+    let start = tag.find(&needle)? + needle.len();
+    let end = tag[start..].find('"')?;
+    Some(decode_entities(&tag[start..start + end]))
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::html_to_markdown;
+
+    #[test]
+    fn converts_puzzle_description() {
+        let html = [
+            "<html><body><main>",
+            "<article class=\"day-desc\"><h2>--- Day 1: Trebuchet?! ---</h2>",
+            "<p>The newly-improved <a href=\"/2023/about\">calibration</a> document is <em>weird</em>.</p>",
+            "<p>For example:</p>",
+            "<pre><code>1abc2\npqr3stu8vwx\n&lt;&gt;\n</code></pre>",
+            "<ul>\n<li>one</li>\n<li>two</li>\n</ul>",
+            "<p>Adding these together produces <code><em>142</em></code> in <code>a &amp; b</code>.</p>",
+            "</article>",
+            "<p>Your puzzle answer was <code>54388</code>.</p>",
+            "</main></body></html>",
+        ]
+        .join("");
+
+        let expected = [
+            "## --- Day 1: Trebuchet?! ---",
+            "",
+            "The newly-improved [calibration](/2023/about) document is *weird*.",
+            "",
+            "For example:",
+            "",
+            "```",
+            "1abc2",
+            "pqr3stu8vwx",
+            "<>",
+            "```",
+            "",
+            "- one",
+            "- two",
+            "",
+            "Adding these together produces *`142`* in `a & b`.",
+            "",
+        ]
+        .join("\n");
+
+        assert_eq!(html_to_markdown(&html), expected);
+    }
+
+    #[test]
+    fn joins_multiple_articles() {
+        let html = "<article><p>one</p></article><p>skipped</p><article><p>two</p></article>";
+        assert_eq!(html_to_markdown(html), "one\n\ntwo\n");
+    }
+}
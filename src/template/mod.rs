@@ -1,36 +1,167 @@
-use crate::Day;
+use crate::PuzzleId;
+use std::time::Duration;
 use std::{env, fs};
 
-pub mod aoc_cli;
+pub mod answers;
+pub mod aoc_client;
+pub mod bench;
+pub mod bench_history;
+pub mod child;
 pub mod commands;
+pub mod examples;
+pub mod input;
+pub mod markdown;
 pub mod readme_benchmarks;
+pub mod registry;
 pub mod runner;
+pub mod trace;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// Returns the path of a data file for a puzzle, i.e. `data/<year>/<folder>/<day>.<extension>`.
+#[must_use]
+pub fn get_data_path(folder: &str, puzzle: PuzzleId, extension: &str) -> String {
+    format!("data/{}/{folder}/{}.{extension}", puzzle.year, puzzle.day)
+}
+
+/// Returns the path of the solution binary for a puzzle, i.e. `src/bin/<year>-<day>.rs`.
+#[must_use]
+pub fn get_bin_path(puzzle: PuzzleId) -> String {
+    format!("src/bin/{puzzle}.rs")
+}
+
 /// Helper function that reads a text file to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn read_file(folder: &str, puzzle: PuzzleId) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd.join(get_data_path(folder, puzzle, "txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Helper function that reads the text file of one part, i.e. `data/<year>/<folder>/<day>-<part>.txt`.
+/// Use this if the parts of a puzzle have different examples.
+#[must_use]
+pub fn read_file_part(folder: &str, puzzle: PuzzleId, part: u8) -> String {
+    let cwd = env::current_dir().unwrap();
+    let filepath = cwd.join(format!(
+        "data/{}/{folder}/{}-{part}.txt",
+        puzzle.year, puzzle.day
+    ));
+    let f = fs::read_to_string(filepath);
+    f.expect("could not open input file")
+}
+
+/// Parses a duration with a unit suffix, e.g. `500ms` or `2s`.
+/// Supported units are `ns`, `us` (or `µs`), `ms` and `s`.
+pub fn parse_duration(s: &str) -> Result<Duration, String> {
+    let split = s
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .ok_or_else(|| format!("missing unit in duration `{s}`, e.g. `500ms`."))?;
+    let (value, unit) = s.split_at(split);
+
+    let value: f64 = value
+        .parse()
+        .map_err(|_| format!("invalid duration `{s}`."))?;
+
+    let secs = match unit {
+        "ns" => value / 1e9,
+        "us" | "µs" => value / 1e6,
+        "ms" => value / 1e3,
+        "s" => value,
+        _ => return Err(format!("unknown unit `{unit}` in duration `{s}`.")),
+    };
+
+    Ok(Duration::from_secs_f64(secs))
+}
+
+/// Creates the constants `YEAR`, `DAY` and `PUZZLE`, registers the solution and sets up the input and runner for each part.
+///
+/// Pass the name of a parse function as third argument to define a two-phase solution:
+/// the input is parsed once by `parse(&str) -> T`, then `part_one(&T)` and `part_two(&T)` share the parsed value.
+/// The runner times the parse phase separately from the parts.
+///
+/// Parts return `Option<T>` or `Result<T, E>` where `T` and `E` implement `Display`.
+/// Append `?` to the parse function if it returns a `Result` too, e.g. `solution!(2023, 2, parse?)`.
+/// If parsing fails, both parts fail with its error.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
+    ($year:expr, $day:expr) => {
+        $crate::solution!(@entry $year, $day, |input, options| {
+            [
+                advent_of_code::template::runner::run_part(part_one, input, PUZZLE, 1, options),
+                advent_of_code::template::runner::run_part(part_two, input, PUZZLE, 2, options),
+            ]
+        });
+    };
+    ($year:expr, $day:expr, $parse:ident) => {
+        $crate::solution!(@entry $year, $day, |input, options| {
+            // closures let the parts borrow the parsed value through deref coercion, e.g. `&[T]` from `Vec<T>`.
+            advent_of_code::template::runner::run_parsed(
+                $parse,
+                |_| true,
+                |parsed| part_one(parsed),
+                |parsed| part_two(parsed),
+                input,
+                PUZZLE,
+                options,
+            )
+        });
+    };
+    ($year:expr, $day:expr, $parse:ident ?) => {
+        $crate::solution!(@entry $year, $day, |input, options| {
+            use advent_of_code::template::runner::{parse_fallible, run_after_parse, run_parsed};
+            run_parsed(
+                parse_fallible($parse),
+                Result::is_ok,
+                |parsed| run_after_parse(parsed, |parsed| part_one(parsed)),
+                |parsed| run_after_parse(parsed, |parsed| part_two(parsed)),
+                input,
+                PUZZLE,
+                options,
+            )
+        });
+    };
+    (@entry $year:expr, $day:expr, $run_parts:expr) => {
+        /// The current year.
+        const YEAR: advent_of_code::Year = advent_of_code::year!($year);
         /// The current day.
         const DAY: advent_of_code::Day = advent_of_code::day!($day);
+        /// The current puzzle.
+        const PUZZLE: advent_of_code::PuzzleId = advent_of_code::PuzzleId::new(YEAR, DAY);
+
+        /// The entry of this solution in the registry of the main binary.
+        pub const SOLUTION: advent_of_code::template::registry::Solution =
+            advent_of_code::template::registry::Solution {
+                puzzle: PUZZLE,
+                run_parts: $run_parts,
+            };
 
         fn main() {
             use advent_of_code::template::runner::*;
-            let input = advent_of_code::template::read_file("inputs", DAY);
-            run_part(part_one, &input, DAY, 1);
-            run_part(part_two, &input, DAY, 2);
+            let options = RunOptions::from_args();
+            let input = read_input(PUZZLE, &options);
+            SOLUTION.run(&input, &options);
         }
     };
 }
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::parse_duration;
+    use std::time::Duration;
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_duration("2s"), Ok(Duration::from_secs(2)));
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("1.5ms"), Ok(Duration::from_micros(1500)));
+        assert_eq!(parse_duration("10us"), Ok(Duration::from_micros(10)));
+        assert_eq!(parse_duration("10µs"), Ok(Duration::from_micros(10)));
+        assert!(parse_duration("10").is_err());
+        assert!(parse_duration("ms").is_err());
+        assert!(parse_duration("10m").is_err());
+    }
+}
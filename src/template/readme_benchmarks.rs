@@ -1,8 +1,9 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+use std::{fs, io, time::Duration};
 
-use crate::Day;
+use crate::template::{get_bin_path, runner::PartRecord};
+use crate::{Day, PuzzleId, Year};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -20,22 +21,66 @@ impl From<std::io::Error> for Error {
 
 #[derive(Clone)]
 pub struct Timings {
-    pub day: Day,
+    pub puzzle: PuzzleId,
+    /// Only two-phase solutions have a separate parse time.
+    pub parse: Option<String>,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
     pub total_nanos: f64,
 }
 
+impl Timings {
+    /// Groups the records of timed parts by puzzle, records of unsolved parts are skipped.
+    pub fn from_records(records: &[PartRecord]) -> Vec<Timings> {
+        let mut timings: Vec<Timings> = vec![];
+
+        for record in records.iter().filter(|r| r.answer.is_some()) {
+            let (Some(year), Some(day)) = (Year::new(record.year), Day::new(record.day)) else {
+                continue;
+            };
+            let puzzle = PuzzleId::new(year, day);
+
+            if timings.last().map(|t| t.puzzle) != Some(puzzle) {
+                timings.push(Timings {
+                    puzzle,
+                    parse: None,
+                    part_1: None,
+                    part_2: None,
+                    total_nanos: 0_f64,
+                });
+            }
+
+            // NOTE: an entry for the puzzle was pushed above.
+            let timing = timings.last_mut().unwrap();
+            // benched parts show their median and spread.
+            let duration = match &record.bench {
+                Some(stats) => stats.spread(),
+                None => format!("{:.1?}", record.duration()),
+            };
+
+            match record.part {
+                1 => timing.part_1 = Some(duration),
+                _ => timing.part_2 = Some(duration),
+            }
+
+            timing.total_nanos += record.duration_nanos as f64;
+
+            // both parts share the parsed input, count it once.
+            if let (Some(parse_nanos), None) = (record.parse_nanos, &timing.parse) {
+                timing.parse = Some(format!("{:.1?}", Duration::from_nanos(parse_nanos)));
+                timing.total_nanos += parse_nanos as f64;
+            }
+        }
+
+        timings
+    }
+}
+
 pub struct TablePosition {
     pos_start: usize,
     pos_end: usize,
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     let matches: Vec<_> = readme.match_indices(MARKER).collect();
 
@@ -65,16 +110,19 @@ fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> St
         MARKER.into(),
         header,
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        "| Day | Parse | Part 1 | Part 2 |".into(),
+        "| :---: | :---: | :---: | :---:  |".into(),
     ];
 
     for timing in timings {
-        let path = get_path_for_bin(timing.day);
+        let path = get_bin_path(timing.puzzle);
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
+            "| [Day {}](./{}) | {} | `{}` | `{}` |",
+            timing.puzzle.day.into_inner(),
             path,
+            timing
+                .parse
+                .map_or_else(|| "-".into(), |parse| format!("`{parse}`")),
             timing.part_1.unwrap_or_else(|| "-".into()),
             timing.part_2.unwrap_or_else(|| "-".into())
         ));
@@ -105,24 +153,29 @@ pub fn update(timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{update_content, Timings, MARKER};
-    use crate::day;
+    use crate::template::bench::BenchStats;
+    use crate::template::runner::PartRecord;
+    use crate::{day, year, PuzzleId};
 
     fn get_mock_timings() -> Vec<Timings> {
         vec![
             Timings {
-                day: day!(1),
+                puzzle: PuzzleId::new(year!(2023), day!(1)),
+                parse: None,
                 part_1: Some("10ms".into()),
                 part_2: Some("20ms".into()),
                 total_nanos: 3e+10,
             },
             Timings {
-                day: day!(2),
+                puzzle: PuzzleId::new(year!(2023), day!(2)),
+                parse: Some("5ms".into()),
                 part_1: Some("30ms".into()),
                 part_2: Some("40ms".into()),
                 total_nanos: 7e+10,
             },
             Timings {
-                day: day!(4),
+                puzzle: PuzzleId::new(year!(2023), day!(4)),
+                parse: None,
                 part_1: Some("40ms".into()),
                 part_2: Some("50ms".into()),
                 total_nanos: 9e+10,
@@ -130,6 +183,62 @@ mod tests {
         ]
     }
 
+    fn get_mock_record(day: u8, part: u8, answer: Option<&str>, duration_nanos: u64) -> PartRecord {
+        PartRecord {
+            year: 2023,
+            day,
+            part,
+            answer: answer.map(str::to_string),
+            error: None,
+            duration_nanos,
+            samples: 1,
+            bench: None,
+            parse_nanos: None,
+            timed_out: false,
+        }
+    }
+
+    #[test]
+    fn timings_from_records() {
+        let timings = Timings::from_records(&[
+            get_mock_record(1, 1, Some("42"), 1_500),
+            get_mock_record(1, 2, None, 2_000),
+            get_mock_record(3, 1, Some("(1, 2)"), 2_000_000),
+            get_mock_record(3, 2, Some("7"), 1_000_000),
+        ]);
+
+        assert_eq!(timings.len(), 2);
+        assert_eq!(timings[0].puzzle, PuzzleId::new(year!(2023), day!(1)));
+        assert_eq!(timings[0].part_1.as_deref(), Some("1.5µs"));
+        assert_eq!(timings[0].part_2, None);
+        assert_eq!(timings[0].total_nanos, 1_500_f64);
+        assert_eq!(timings[1].part_1.as_deref(), Some("2.0ms"));
+        assert_eq!(timings[1].part_2.as_deref(), Some("1.0ms"));
+        assert_eq!(timings[1].total_nanos, 3_000_000_f64);
+    }
+
+    #[test]
+    fn timings_count_shared_parse_once() {
+        let mut part_1 = get_mock_record(1, 1, Some("1"), 1_000);
+        let mut part_2 = get_mock_record(1, 2, Some("2"), 2_000);
+        part_1.parse_nanos = Some(500);
+        part_2.parse_nanos = Some(500);
+
+        let timings = Timings::from_records(&[part_1, part_2]);
+        assert_eq!(timings[0].parse.as_deref(), Some("500.0ns"));
+        assert_eq!(timings[0].total_nanos, 3_500_f64);
+    }
+
+    #[test]
+    fn timings_show_spread_of_benched_records() {
+        let mut record = get_mock_record(1, 1, Some("42"), 1_500);
+        record.bench = BenchStats::from_samples(&[1_000, 1_500, 2_000]);
+        record.samples = 3;
+
+        let timings = Timings::from_records(&[record]);
+        assert_eq!(timings[0].part_1.as_deref(), Some("1.5µs ± 408.0ns"));
+    }
+
     #[test]
     #[should_panic]
     fn errors_if_marker_not_present() {
@@ -170,11 +279,11 @@ mod tests {
             "<!--- benchmarking table --->",
             "## Benchmarks",
             "",
-            "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/2023-01.rs) | - | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2023-02.rs) | `5ms` | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/2023-04.rs) | - | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
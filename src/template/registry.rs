@@ -0,0 +1,36 @@
+/// Registry of solutions that lets the main binary run days in-process.
+/// Every `solution!` invocation defines a [`Solution`], the main binary collects them into a [`Registry`].
+use crate::template::runner::{PartRecord, RunOptions};
+use crate::template::trace;
+use crate::PuzzleId;
+
+/// The entry point of one day's solution.
+pub struct Solution {
+    pub puzzle: PuzzleId,
+    /// Runs both parts, including the parse phase of two-phase solutions.
+    pub run_parts: fn(&str, &RunOptions) -> [PartRecord; 2],
+}
+
+impl Solution {
+    /// Runs both parts against `input`.
+    pub fn run(&self, input: &str, options: &RunOptions) -> [PartRecord; 2] {
+        if let Some(filter) = &options.trace {
+            trace::enable(filter.clone());
+        }
+        (self.run_parts)(input, options)
+    }
+}
+
+pub struct Registry {
+    solutions: &'static [Solution],
+}
+
+impl Registry {
+    pub const fn new(solutions: &'static [Solution]) -> Self {
+        Self { solutions }
+    }
+
+    pub fn get(&self, puzzle: PuzzleId) -> Option<&Solution> {
+        self.solutions.iter().find(|s| s.puzzle == puzzle)
+    }
+}
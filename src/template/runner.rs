@@ -1,103 +1,515 @@
 /// Encapsulates code that interacts with solution functions.
-use crate::template::{aoc_cli, ANSI_ITALIC, ANSI_RESET};
-use crate::Day;
+use crate::template::answers::{Ledger, Verdict};
+use crate::template::bench::{self, BenchStats};
+use crate::template::input::InputSource;
+use crate::template::trace::{self, Phase, TraceFilter};
+use crate::template::{aoc_client, parse_duration, ANSI_ITALIC, ANSI_RESET};
+use crate::PuzzleId;
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use std::env;
+use std::error::Error;
 use std::fmt::Display;
 use std::io::{stdout, Write};
-use std::process::Output;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::process;
+use std::str::FromStr;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
 
 use super::ANSI_BOLD;
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
-    let part_str = format!("Part {part}");
+/// How the outcome of a part is printed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable output.
+    #[default]
+    Text,
+    /// One [`PartRecord`] per line, encoded as JSON.
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = OutputFormatFromStrError;
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => Err(OutputFormatFromStrError),
+        }
+    }
+}
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+/// An error which can be returned when parsing an [`OutputFormat`].
+#[derive(Debug)]
+pub struct OutputFormatFromStrError;
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+impl std::error::Error for OutputFormatFromStrError {}
+
+impl Display for OutputFormatFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting an output format of either `text` or `json`")
     }
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
-fn run_timed<I: Clone, T>(
-    func: impl Fn(I) -> T,
+/// Options that control how solution parts are run.
+#[derive(Debug, Clone, Default)]
+pub struct RunOptions {
+    /// Bench every part instead of running it once.
+    pub time: bool,
+    /// Submit the answer of this part.
+    pub submit: Option<u8>,
+    pub format: OutputFormat,
+    /// How long every part is benched, defaults to [`bench::DEFAULT_BUDGET`].
+    pub bench_time: Option<Duration>,
+    pub input: InputSource,
+    /// Print the [`crate::trace!`] lines of the solution that match the filter.
+    pub trace: Option<TraceFilter>,
+}
+
+impl RunOptions {
+    /// Reads the options from the arguments passed to a solution binary.
+    pub fn from_args() -> Self {
+        let mut raw_args: Vec<_> = env::args_os().skip(1).collect();
+        let trace = trace::take_arg(&mut raw_args);
+        let mut args = pico_args::Arguments::from_vec(raw_args);
+
+        let Ok(submit) = args.opt_value_from_str("--submit") else {
+            eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+            process::exit(1);
+        };
+
+        let format = match args.opt_value_from_str("--format") {
+            Ok(format) => format.unwrap_or_default(),
+            Err(e) => {
+                eprintln!("{e}");
+                process::exit(1);
+            }
+        };
+
+        let bench_time = match args.opt_value_from_fn("--bench-time", parse_duration) {
+            Ok(bench_time) => bench_time,
+            Err(e) => {
+                eprintln!("{e}");
+                process::exit(1);
+            }
+        };
+
+        let input = match InputSource::parse(&mut args) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("{e}");
+                process::exit(1);
+            }
+        };
+
+        let time = args.contains("--time");
+
+        let trace = match trace::check(trace, time) {
+            Ok(trace) => trace,
+            Err(e) => {
+                eprintln!("{e}");
+                process::exit(1);
+            }
+        };
+
+        Self {
+            time,
+            submit,
+            format,
+            bench_time,
+            input,
+            trace,
+        }
+    }
+}
+
+/// Reads the input selected in `options`, exits if it can not be read.
+pub fn read_input(puzzle: PuzzleId, options: &RunOptions) -> String {
+    match options.input.read(puzzle) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!(
+                "Could not read input {}: {e}",
+                options.input.describe(puzzle)
+            );
+            process::exit(1);
+        }
+    }
+}
+
+/// Machine-readable outcome of running a solution part.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartRecord {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    /// The error the part failed with, if it returned a `Result`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// The execution time in nanoseconds, the median of all samples if the part was benched.
+    pub duration_nanos: u64,
+    pub samples: u128,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bench: Option<BenchStats>,
+    /// The time spent parsing the input in nanoseconds, shared by both parts of a two-phase solution.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parse_nanos: Option<u64>,
+    /// Whether the part was killed by `cargo all --timeout`, its duration is the timeout.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub timed_out: bool,
+}
+
+impl PartRecord {
+    pub fn duration(&self) -> Duration {
+        Duration::from_nanos(self.duration_nanos)
+    }
+
+    /// The record of a part that could not be run.
+    pub fn failed(puzzle: PuzzleId, part: u8, error: String) -> Self {
+        Self {
+            year: puzzle.year.into_inner(),
+            day: puzzle.day.into_inner(),
+            part,
+            answer: None,
+            error: Some(error),
+            duration_nanos: 0,
+            samples: 0,
+            bench: None,
+            parse_nanos: None,
+            timed_out: false,
+        }
+    }
+
+    /// The record of a part that did not finish within `timeout`.
+    pub fn timed_out(puzzle: PuzzleId, part: u8, timeout: Duration) -> Self {
+        Self {
+            error: None,
+            #[allow(clippy::cast_possible_truncation)]
+            duration_nanos: timeout.as_nanos() as u64,
+            timed_out: true,
+            ..Self::failed(puzzle, part, String::new())
+        }
+    }
+
+    pub fn outcome(&self) -> Outcome {
+        match (&self.answer, &self.error) {
+            (Some(answer), _) => Outcome::Answer(answer.clone()),
+            (None, Some(e)) => Outcome::Error(e.clone()),
+            (None, None) => Outcome::Unsolved,
+        }
+    }
+}
+
+/// What a solution part produced.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Answer(String),
+    /// The part returned `None`, e.g. because it is not implemented yet.
+    Unsolved,
+    /// The part returned an error.
+    Error(String),
+}
+
+/// The return types of solution parts: `Option<T>` and `Result<T, E>` with displayable answers and errors.
+pub trait PartResult {
+    fn outcome(self) -> Outcome;
+}
+
+impl<T: Display> PartResult for Option<T> {
+    fn outcome(self) -> Outcome {
+        match self {
+            Some(answer) => Outcome::Answer(answer.to_string()),
+            None => Outcome::Unsolved,
+        }
+    }
+}
+
+impl<T: Display, E: Display> PartResult for Result<T, E> {
+    fn outcome(self) -> Outcome {
+        match self {
+            Ok(answer) => Outcome::Answer(answer.to_string()),
+            Err(e) => Outcome::Error(e.to_string()),
+        }
+    }
+}
+
+impl PartResult for Outcome {
+    fn outcome(self) -> Outcome {
+        self
+    }
+}
+
+/// Displays an error followed by the errors that caused it, e.g. `could not parse card: invalid digit found in string`.
+/// Return `Result<T, ErrorChain<E>>` from a part to report the causes of its errors, `?` wraps them.
+#[derive(Debug)]
+pub struct ErrorChain<E>(pub E);
+
+impl<E> From<E> for ErrorChain<E> {
+    fn from(error: E) -> Self {
+        Self(error)
+    }
+}
+
+impl<E: Error> Display for ErrorChain<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)?;
+        let mut source = self.0.source();
+        while let Some(cause) = source {
+            write!(f, ": {cause}")?;
+            source = cause.source();
+        }
+        Ok(())
+    }
+}
+
+pub fn run_part<I: Clone, R: PartResult>(
+    func: impl Fn(I) -> R,
     input: I,
-    hook: impl Fn(&T),
-) -> (T, Duration, u128) {
-    let timer = Instant::now();
-    let result = func(input.clone());
-    let base_time = timer.elapsed();
+    puzzle: PuzzleId,
+    part: u8,
+    options: &RunOptions,
+) -> PartRecord {
+    run_part_with_parse(func, input, puzzle, part, None, options)
+}
+
+/// Runs a two-phase solution: the input is parsed once, timed on its own, and shared by both parts.
+/// The parse is only benched if `is_parsed` accepts its result, so a failed fallible parse is not benched.
+pub fn run_parsed<'a, P, A: PartResult, B: PartResult>(
+    parse: impl Fn(&'a str) -> P,
+    is_parsed: impl Fn(&P) -> bool,
+    part_one: impl Fn(&P) -> A,
+    part_two: impl Fn(&P) -> B,
+    input: &'a str,
+    puzzle: PuzzleId,
+    options: &RunOptions,
+) -> [PartRecord; 2] {
+    let is_text = options.format == OutputFormat::Text;
 
-    hook(&result);
+    trace::set_phase(Phase::Parse);
+    let (parsed, parse_time, stats) = run_timed(
+        |input| catch_unwind(AssertUnwindSafe(|| parse(input))),
+        input,
+        options,
+        |parsed| {
+            if is_text {
+                print!("Parse:");
+            }
+            parsed
+        },
+        |parsed| parsed.as_ref().is_ok_and(&is_parsed),
+    );
+
+    if is_text {
+        println!("\rParse:{}", format_duration(&parse_time, stats.as_ref()));
+    }
+
+    match &parsed {
+        Ok(parsed) => [
+            run_part_with_parse(part_one, parsed, puzzle, 1, Some(parse_time), options),
+            run_part_with_parse(part_two, parsed, puzzle, 2, Some(parse_time), options),
+        ],
+        // neither part can run without the parsed input.
+        Err(payload) => {
+            let error = format!("parse {}", panic_message(payload.as_ref()));
+            [1, 2].map(|part| {
+                let fail = |()| Outcome::Error(error.clone());
+                run_part_with_parse(fail, (), puzzle, part, Some(parse_time), options)
+            })
+        }
+    }
+}
+
+/// Wraps a fallible parse function for [`run_parsed`], keeping the error message to report it for both parts.
+/// Pass `Result::is_ok` as `is_parsed` so failed parses are not benched.
+pub fn parse_fallible<'a, P, E: Display>(
+    parse: impl Fn(&'a str) -> Result<P, E>,
+) -> impl Fn(&'a str) -> Result<P, String> {
+    move |input| parse(input).map_err(|e| format!("could not parse input: {e}"))
+}
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+/// Runs a part on the result of a fallible parse function, the part fails if parsing failed.
+pub fn run_after_parse<P, R: PartResult>(
+    parsed: &Result<P, String>,
+    part: impl Fn(&P) -> R,
+) -> Outcome {
+    match parsed {
+        Ok(parsed) => part(parsed).outcome(),
+        Err(e) => Outcome::Error(e.clone()),
+    }
+}
+
+fn run_part_with_parse<I: Clone, R: PartResult>(
+    func: impl Fn(I) -> R,
+    input: I,
+    puzzle: PuzzleId,
+    part: u8,
+    parse_time: Option<Duration>,
+    options: &RunOptions,
+) -> PartRecord {
+    let part_str = format!("Part {part}");
+    let is_text = options.format == OutputFormat::Text;
+
+    trace::set_phase(if part == 1 {
+        Phase::Part1
     } else {
-        (base_time, 1)
+        Phase::Part2
+    });
+
+    // a panicking part fails on its own instead of taking down the whole run.
+    let (outcome, duration, stats) = run_timed(
+        |input| catch_unwind(AssertUnwindSafe(|| func(input))),
+        input,
+        options,
+        |result| {
+            let outcome = caught_outcome(result);
+            if is_text {
+                print_result(&outcome, &part_str, "");
+            }
+            outcome
+        },
+        // failed parts, including those that panicked or could not use a failed parse, are not benched.
+        |outcome| !matches!(outcome, Outcome::Error(_)),
+    );
+
+    if is_text {
+        print_result(
+            &outcome,
+            &part_str,
+            &format_duration(&duration, stats.as_ref()),
+        );
+    }
+
+    let (answer, error) = match outcome {
+        Outcome::Answer(answer) => (Some(answer), None),
+        Outcome::Unsolved => (None, None),
+        Outcome::Error(e) => (None, Some(e)),
+    };
+
+    let record = PartRecord {
+        year: puzzle.year.into_inner(),
+        day: puzzle.day.into_inner(),
+        part,
+        answer,
+        error,
+        #[allow(clippy::cast_possible_truncation)]
+        duration_nanos: duration.as_nanos() as u64,
+        samples: stats.map_or(1, |stats| u128::from(stats.samples)),
+        bench: stats,
+        #[allow(clippy::cast_possible_truncation)]
+        parse_nanos: parse_time.map(|duration| duration.as_nanos() as u64),
+        timed_out: false,
     };
 
-    (result, run.0, run.1)
+    if options.format == OutputFormat::Json {
+        // serializing a struct of plain values can not fail.
+        println!("{}", serde_json::to_string(&record).unwrap());
+    }
+
+    if let Some(answer) = &record.answer {
+        submit_result(answer, puzzle, part, options);
+    }
+
+    record
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+fn caught_outcome<R: PartResult>(result: Result<R, Box<dyn Any + Send>>) -> Outcome {
+    match result {
+        Ok(result) => result.outcome(),
+        Err(payload) => Outcome::Error(panic_message(payload.as_ref())),
+    }
+}
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    let message = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("with a non-string payload");
+    format!("panicked: {message}")
+}
 
-    let bench_iterations = cmp::min(
-        10000,
-        cmp::max(
-            Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10),
-            10,
-        ),
-    );
+/// Run a solution part. The behavior differs depending on whether the part is timed:
+///  1. by default, the function is executed once.
+///  2. with `--time`, the function is benched for the configured budget (1 second by default) and the median is reported.
+///
+/// Results that fail `is_benchable`, e.g. caught panics, are not benched.
+fn run_timed<I: Clone, T, U>(
+    func: impl Fn(I) -> T,
+    input: I,
+    options: &RunOptions,
+    finish: impl FnOnce(T) -> U,
+    is_benchable: impl Fn(&U) -> bool,
+) -> (U, Duration, Option<BenchStats>) {
+    let timer = Instant::now();
+    let result = func(input.clone());
+    let base_time = timer.elapsed();
 
-    let mut timers: Vec<Duration> = vec![];
+    let result = finish(result);
 
-    for _ in 0..bench_iterations {
-        // need a clone here to make the borrow checker happy.
-        let cloned = input.clone();
-        let timer = Instant::now();
-        func(cloned);
-        timers.push(timer.elapsed());
+    if !options.time || !is_benchable(&result) {
+        return (result, base_time, None);
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    if options.format == OutputFormat::Text {
+        let mut stdout = stdout();
+        print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+        let _ = stdout.flush();
+    }
+
+    let budget = options.bench_time.unwrap_or(bench::DEFAULT_BUDGET);
+    let stats = bench::run(func, input, base_time, budget);
+
+    (result, stats.median(), Some(stats))
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+fn format_duration(duration: &Duration, stats: Option<&BenchStats>) -> String {
+    match stats {
+        None => format!(" ({duration:.1?})"),
+        Some(stats) if stats.outliers == 0 => {
+            format!(" ({} @ {} samples)", stats.spread(), stats.samples)
+        }
+        Some(stats) => format!(
+            " ({} @ {} samples, {} outliers)",
+            stats.spread(),
+            stats.samples,
+            stats.outliers
+        ),
+    }
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
-    } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+/// Prints a record received from a solution that ran in another process, like the runner prints its parts.
+pub fn print_record(record: &PartRecord) {
+    let part = format!("Part {}", record.part);
+
+    if record.part == 1 {
+        if let Some(parse_nanos) = record.parse_nanos {
+            println!(
+                "Parse:{}",
+                format_duration(&Duration::from_nanos(parse_nanos), None)
+            );
+        }
+    }
+
+    if record.timed_out {
+        println!("{part}: ✖ TIMEOUT ({:.1?})", record.duration());
+        return;
     }
+
+    print_result(
+        &record.outcome(),
+        &part,
+        &format_duration(&record.duration(), record.bench.as_ref()),
+    );
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn print_result(outcome: &Outcome, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
-    match result {
-        Some(result) => {
-            if result.to_string().contains('\n') {
+    match outcome {
+        Outcome::Answer(result) => {
+            if result.contains('\n') {
                 let str = format!("{part}: ▼ {duration_str}");
                 if is_intermediate_result {
                     print!("{str}");
@@ -116,7 +528,7 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 }
             }
         }
-        None => {
+        Outcome::Unsolved => {
             if is_intermediate_result {
                 print!("{part}: ✖");
             } else {
@@ -124,44 +536,132 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 println!("{part}: ✖             ");
             }
         }
+        Outcome::Error(e) => {
+            if is_intermediate_result {
+                print!("{part}: ✖ error");
+            } else {
+                print!("\r");
+                println!("{part}: ✖ {e}{duration_str}");
+            }
+        }
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
+/// Try to submit one part of the solution if:
+///  1. the part was passed to `--submit`.
+///  2. a session cookie is configured.
+///  3. the answer ledger does not already know the answer to be correct or wrong.
+fn submit_result(
+    answer: &str,
+    puzzle: PuzzleId,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
-
-    if !args.contains(&"--submit".into()) {
+    options: &RunOptions,
+) -> Option<Result<aoc_client::Submission, aoc_client::AocClientError>> {
+    if options.submit != Some(part) {
         return None;
     }
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    if options.input != InputSource::Puzzle {
+        eprintln!(
+            "Refusing to submit {answer}: it was computed from {}, not from the puzzle input.",
+            options.input.describe(puzzle)
+        );
+        return None;
     }
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
-
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    let mut ledger = match Ledger::load(puzzle) {
+        Ok(ledger) => ledger,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
     };
 
-    if part_submit != part {
-        return None;
+    match ledger.part(part).check(answer) {
+        Verdict::Correct => {
+            println!("{answer} is already on record as the correct answer, skipping submission.");
+            return None;
+        }
+        Verdict::Wrong(reason) => {
+            eprintln!("Refusing to submit {answer}: {reason}.");
+            return None;
+        }
+        Verdict::Unknown => {}
+    }
+
+    println!("Submitting result...");
+    let submission = aoc_client::submit(puzzle, part, answer);
+
+    match &submission {
+        Ok(submission) => {
+            println!("{}", submission.message);
+            ledger.part_mut(part).record(answer, submission.outcome);
+            if let Err(e) = ledger.save(puzzle) {
+                eprintln!("{e}");
+            }
+        }
+        Err(e) => eprintln!("failed to submit result: {e}"),
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    Some(submission)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::error::Error;
+    use std::fmt;
+    use std::num::ParseIntError;
+
+    use super::{ErrorChain, Outcome, PartResult};
+
+    #[test]
+    fn converts_part_results() {
+        assert_eq!(Some(42).outcome(), Outcome::Answer("42".into()));
+        assert_eq!(None::<u32>.outcome(), Outcome::Unsolved);
+        assert_eq!(Ok::<_, String>(42).outcome(), Outcome::Answer("42".into()));
+        assert_eq!(
+            Err::<u32, _>("unknown card `X`").outcome(),
+            Outcome::Error("unknown card `X`".into())
+        );
+
+        // errors only have to be displayable.
+        struct UnknownCard(char);
+
+        impl fmt::Display for UnknownCard {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "unknown card `{}`", self.0)
+            }
+        }
+
+        assert_eq!(
+            Err::<u32, _>(UnknownCard('X')).outcome(),
+            Outcome::Error("unknown card `X`".into())
+        );
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    #[test]
+    fn reports_error_sources() {
+        #[derive(Debug)]
+        struct CardError(ParseIntError);
+
+        impl fmt::Display for CardError {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "could not parse card")
+            }
+        }
+
+        impl Error for CardError {
+            fn source(&self) -> Option<&(dyn Error + 'static)> {
+                Some(&self.0)
+            }
+        }
+
+        let parse_card = |card: &str| -> Result<u32, ErrorChain<CardError>> {
+            Ok(card.parse().map_err(CardError)?)
+        };
+        assert_eq!(
+            parse_card("X").outcome(),
+            Outcome::Error("could not parse card: invalid digit found in string".into())
+        );
+    }
 }
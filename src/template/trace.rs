@@ -0,0 +1,170 @@
+/// Diagnostic output for solutions, printed when a solution is run with `cargo trace <day> [--trace <filter>]`.
+/// Use [`crate::trace!`] like `println!`, the lines are printed to stderr with the phase and location they come from:
+///
+/// ```text
+/// part 2 src/bin/2023-03.rs:41 gear at (3, 1) with ratio 16345
+/// ```
+///
+/// Tracing is only compiled in with the `trace` feature, which `cargo trace` enables in a target directory of its own.
+/// `cargo solve`, `cargo all` and `cargo time` build without it, so trace calls cost nothing in benchmarks.
+use std::ffi::OsString;
+use std::fmt::Arguments;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::OnceLock;
+
+/// Only trace lines that contain the filter are printed, all lines are printed without a filter.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TraceFilter(pub Option<String>);
+
+impl TraceFilter {
+    pub fn matches(&self, line: &str) -> bool {
+        self.0.as_deref().is_none_or(|filter| line.contains(filter))
+    }
+}
+
+/// The phase of a solution that is currently running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Phase {
+    Parse = 1,
+    Part1 = 2,
+    Part2 = 3,
+}
+
+static FILTER: OnceLock<TraceFilter> = OnceLock::new();
+static PHASE: AtomicU8 = AtomicU8::new(0);
+
+/// Whether tracing is compiled into this build.
+pub const IS_AVAILABLE: bool = cfg!(feature = "trace");
+
+/// Turns tracing on for the rest of the process.
+pub fn enable(filter: TraceFilter) {
+    let _ = FILTER.set(filter);
+}
+
+pub fn is_enabled() -> bool {
+    FILTER.get().is_some()
+}
+
+pub fn set_phase(phase: Phase) {
+    PHASE.store(phase as u8, Ordering::Relaxed);
+}
+
+fn phase_label() -> &'static str {
+    match PHASE.load(Ordering::Relaxed) {
+        1 => "parse",
+        2 => "part 1",
+        3 => "part 2",
+        _ => "-",
+    }
+}
+
+/// Prints a trace line if it matches the filter. Called by [`crate::trace!`].
+pub fn emit(file: &str, line: u32, message: Arguments) {
+    let Some(filter) = FILTER.get() else {
+        return;
+    };
+
+    // Solutions compiled into the main binary are included by their absolute path.
+    let file = file
+        .strip_prefix(concat!(env!("CARGO_MANIFEST_DIR"), "/"))
+        .unwrap_or(file);
+    let line = format!("{} {file}:{line} {message}", phase_label());
+    if filter.matches(&line) {
+        eprintln!("{line}");
+    }
+}
+
+/// Rejects `--trace` in builds without tracing, and together with `--time`.
+/// Builds with tracing trace every line unless a filter is passed, or the solution is timed.
+pub fn check(filter: Option<TraceFilter>, is_timed: bool) -> Result<Option<TraceFilter>, String> {
+    match filter {
+        Some(_) if !IS_AVAILABLE => Err(
+            "`--trace` needs the `trace` feature, run `cargo trace <day>` or pass `--features trace`."
+                .into(),
+        ),
+        Some(_) if is_timed => Err(
+            "`--trace` can not be combined with `--time`, the trace output would be benched too."
+                .into(),
+        ),
+        None if IS_AVAILABLE && !is_timed => Ok(Some(TraceFilter::default())),
+        filter => Ok(filter),
+    }
+}
+
+/// Removes `--trace [filter]` or `--trace=<filter>` from the arguments.
+/// The filter is the argument following `--trace` unless it is another option, so pass the day before `--trace`.
+pub fn take_arg(args: &mut Vec<OsString>) -> Option<TraceFilter> {
+    let index = args.iter().position(|arg| {
+        arg == "--trace" || arg.to_str().is_some_and(|arg| arg.starts_with("--trace="))
+    })?;
+
+    let arg = args.remove(index);
+    if let Some(filter) = arg.to_str().and_then(|arg| arg.strip_prefix("--trace=")) {
+        return Some(TraceFilter(Some(filter.to_string())));
+    }
+
+    let filter = match args.get(index).and_then(|next| next.to_str()) {
+        Some(next) if !next.starts_with('-') => Some(args.remove(index).into_string().ok()?),
+        _ => None,
+    };
+
+    Some(TraceFilter(filter))
+}
+
+/// Prints a diagnostic line while running `cargo trace <day> [--trace <filter>]`, see [`crate::template::trace`].
+/// Without the `trace` feature, the call is compiled out.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::template::trace::IS_AVAILABLE && $crate::template::trace::is_enabled() {
+            $crate::template::trace::emit(file!(), line!(), format_args!($($arg)*));
+        }
+    };
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{take_arg, TraceFilter};
+    use std::ffi::OsString;
+
+    fn take(args: &[&str]) -> (Option<TraceFilter>, Vec<OsString>) {
+        let mut args: Vec<OsString> = args.iter().map(OsString::from).collect();
+        let filter = take_arg(&mut args);
+        (filter, args)
+    }
+
+    #[test]
+    fn takes_trace_argument() {
+        assert_eq!(
+            take(&["solve", "3"]),
+            (None, vec!["solve".into(), "3".into()])
+        );
+        assert_eq!(
+            take(&["solve", "3", "--trace"]),
+            (Some(TraceFilter(None)), vec!["solve".into(), "3".into()])
+        );
+        assert_eq!(
+            take(&["solve", "3", "--trace", "part 2", "--time"]),
+            (
+                Some(TraceFilter(Some("part 2".into()))),
+                vec!["solve".into(), "3".into(), "--time".into()]
+            )
+        );
+        assert_eq!(
+            take(&["--trace", "--example"]),
+            (Some(TraceFilter(None)), vec!["--example".into()])
+        );
+        assert_eq!(
+            take(&["--trace=gear"]).0,
+            Some(TraceFilter(Some("gear".into())))
+        );
+    }
+
+    #[test]
+    fn filters_lines() {
+        assert!(TraceFilter(None).matches("part 1 src/bin/2023-03.rs:1 x"));
+        assert!(TraceFilter(Some("part 2".into())).matches("part 2 src/bin/2023-03.rs:1 x"));
+        assert!(!TraceFilter(Some("part 2".into())).matches("part 1 src/bin/2023-03.rs:1 x"));
+    }
+}